@@ -0,0 +1,191 @@
+use crate::{img::BWImageSize, BWImage};
+
+/// Neighbours of a pixel connecting it to a component
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Left, right, top and bottom neighbours
+    Four,
+    /// The 4 neighbours and the diagonal ones
+    #[default]
+    Eight,
+}
+
+/// Statistics of a connected component
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Component {
+    /// Top left corner of the bounding box
+    pub origin: (u32, u32),
+    /// Size of the bounding box
+    pub size: BWImageSize,
+    /// Number of pixels of the component
+    pub pixels: u64,
+    /// Mean position of the pixels
+    pub centroid: (f64, f64),
+}
+
+/// Connected components of the pixels of one color, ordered by their first pixel
+/// from the top left
+#[derive(Clone, Debug)]
+pub struct Components {
+    size: BWImageSize,
+    /// Index of the component + 1 for every pixel, 0 for the background
+    labels: Vec<u32>,
+    pub components: Vec<Component>,
+}
+
+impl Components {
+    /// Get the index of the component of the pixel, `None` for the background
+    /// or if the pixel is out of bounds
+    pub fn label(&self, x: u32, y: u32) -> Option<usize> {
+        if x >= self.size.width || y >= self.size.height {
+            return None;
+        }
+        let label = self.labels[y as usize * self.size.width as usize + x as usize];
+        label.checked_sub(1).map(|l| l as usize)
+    }
+
+    /// Get an image with the pixels of the components matching `keep` white
+    pub fn mask(&self, mut keep: impl FnMut(&Component) -> bool) -> BWImage {
+        let kept: Vec<bool> = self.components.iter().map(&mut keep).collect();
+        let mut img = BWImage::new(self.size, false);
+        for y in 0..self.size.height {
+            for x in 0..self.size.width {
+                if self.label(x, y).is_some_and(|l| kept[l]) {
+                    img.set(x, y, true);
+                }
+            }
+        }
+        img
+    }
+}
+
+fn find(parents: &mut [u32], mut label: u32) -> u32 {
+    while parents[label as usize] != label {
+        let parent = parents[label as usize];
+        parents[label as usize] = parents[parent as usize];
+        label = parent;
+    }
+    label
+}
+
+impl BWImage {
+    /// Label the connected components of the white pixels, or the black ones if `white` is false
+    pub fn components(&self, white: bool, connectivity: Connectivity) -> Components {
+        let (width, height) = (self.size.width as usize, self.size.height as usize);
+        let mut labels = vec![0u32; width * height];
+        // union find of the provisional labels, 0 is the background
+        let mut parents = vec![0u32];
+
+        for y in 0..height {
+            for x in 0..width {
+                if self.get(x as u32, y as u32) != white {
+                    continue;
+                }
+                let idx = y * width + x;
+                let mut neighbours = [0; 4];
+                if x > 0 {
+                    neighbours[0] = labels[idx - 1];
+                }
+                if y > 0 {
+                    neighbours[1] = labels[idx - width];
+                    if connectivity == Connectivity::Eight {
+                        if x > 0 {
+                            neighbours[2] = labels[idx - width - 1];
+                        }
+                        if x + 1 < width {
+                            neighbours[3] = labels[idx - width + 1];
+                        }
+                    }
+                }
+
+                let mut label = 0;
+                for neighbour in neighbours.into_iter().filter(|n| *n != 0) {
+                    let root = find(&mut parents, neighbour);
+                    if label == 0 {
+                        label = root;
+                    } else if root != label {
+                        let (low, high) = (label.min(root), label.max(root));
+                        parents[high as usize] = low;
+                        label = low;
+                    }
+                }
+                if label == 0 {
+                    label = parents.len() as u32;
+                    parents.push(label);
+                }
+                labels[idx] = label;
+            }
+        }
+
+        // replace the provisional labels with the component indices
+        let mut indices = vec![0u32; parents.len()];
+        let mut components: Vec<Component> = vec![];
+        let mut sums: Vec<(u64, u64)> = vec![];
+        let mut bounds: Vec<(u32, u32, u32, u32)> = vec![];
+        for (idx, label) in labels.iter_mut().enumerate() {
+            if *label == 0 {
+                continue;
+            }
+            let root = find(&mut parents, *label) as usize;
+            if indices[root] == 0 {
+                components.push(Component {
+                    origin: (0, 0),
+                    size: BWImageSize {
+                        width: 0,
+                        height: 0,
+                    },
+                    pixels: 0,
+                    centroid: (0.0, 0.0),
+                });
+                sums.push((0, 0));
+                bounds.push((u32::MAX, u32::MAX, 0, 0));
+                indices[root] = components.len() as u32;
+            }
+            *label = indices[root];
+
+            let i = *label as usize - 1;
+            let (x, y) = ((idx % width) as u32, (idx / width) as u32);
+            components[i].pixels += 1;
+            sums[i].0 += x as u64;
+            sums[i].1 += y as u64;
+            let b = &mut bounds[i];
+            *b = (b.0.min(x), b.1.min(y), b.2.max(x), b.3.max(y));
+        }
+
+        for ((component, sum), b) in components.iter_mut().zip(sums).zip(bounds) {
+            component.origin = (b.0, b.1);
+            component.size = BWImageSize {
+                width: b.2 - b.0 + 1,
+                height: b.3 - b.1 + 1,
+            };
+            component.centroid = (
+                sum.0 as f64 / component.pixels as f64,
+                sum.1 as f64 / component.pixels as f64,
+            );
+        }
+
+        Components {
+            size: self.size,
+            labels,
+            components,
+        }
+    }
+
+    /// Remove the components of white pixels(or black if `white` is false)
+    /// with less than `min_pixels` pixels, they are painted with the other color
+    pub fn despeckle(&self, white: bool, connectivity: Connectivity, min_pixels: u64) -> BWImage {
+        let components = self.components(white, connectivity);
+        let mut img = self.clone();
+        for y in 0..self.size.height {
+            for x in 0..self.size.width {
+                if components
+                    .label(x, y)
+                    .is_some_and(|l| components.components[l].pixels < min_pixels)
+                {
+                    img.set(x, y, !white);
+                }
+            }
+        }
+        img
+    }
+}
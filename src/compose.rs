@@ -0,0 +1,85 @@
+use crate::{view::BWView, BWImage};
+
+/// Operation combining the pixels of a source with the destination, white is 1
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RasterOp {
+    /// Replace the destination with the source
+    #[default]
+    Copy,
+    And,
+    Or,
+    Xor,
+    /// Clear the destination where the source is white
+    AndNot,
+}
+
+impl RasterOp {
+    #[inline(always)]
+    pub fn apply(&self, dst: u8, src: u8) -> u8 {
+        match self {
+            Self::Copy => src,
+            Self::And => dst & src,
+            Self::Or => dst | src,
+            Self::Xor => dst ^ src,
+            Self::AndNot => dst & !src,
+        }
+    }
+}
+
+impl BWImage {
+    /// Combine `len` pixels of the row from `(x, y)` with the high bits of the byte.
+    /// The pixels must be in bounds
+    pub(crate) fn apply_bits(&mut self, x: u32, y: u32, byte: u8, len: usize, op: RasterOp) {
+        let idx = y as usize * self.size.get_padded_row_len() + x as usize / 8;
+        let shift = x % 8;
+        let mask = (0xff00u16 >> len) as u8;
+        let (bits, mask) = (
+            ((byte & mask) as u16) << 8 >> shift,
+            (mask as u16) << 8 >> shift,
+        );
+
+        for (i, bits, mask) in [
+            (idx, (bits >> 8) as u8, (mask >> 8) as u8),
+            (idx + 1, bits as u8, mask as u8),
+        ] {
+            if mask != 0 {
+                let dst = self.pixels[i];
+                self.pixels[i] = dst & !mask | op.apply(dst, bits) & mask;
+            }
+        }
+    }
+
+    /// Combine an image with the pixels at `(x, y)`, the parts out of this image are clipped
+    #[inline(always)]
+    pub fn blit(&mut self, src: &BWImage, x: i64, y: i64, op: RasterOp) {
+        self.blit_view(&src.as_view(), x, y, op)
+    }
+
+    /// Combine a view with the pixels at `(x, y)`, the parts out of this image are clipped
+    pub fn blit_view(&mut self, src: &BWView, x: i64, y: i64, op: RasterOp) {
+        if x <= -(src.size().width as i64)
+            || y <= -(src.size().height as i64)
+            || x >= self.size.width as i64
+            || y >= self.size.height as i64
+        {
+            return;
+        }
+
+        let (src_x, src_y) = ((-x).max(0), (-y).max(0));
+        let (dst_x, dst_y) = (x.max(0) as u32, y.max(0) as u32);
+        let visible = src.view(
+            src_x as u32,
+            src_y as u32,
+            self.size.width - dst_x,
+            self.size.height - dst_y,
+        );
+        self.apply_view(&visible, dst_x, dst_y, op);
+    }
+
+    /// Get a copy of the image combined with another one at `(x, y)`
+    pub fn compose(&self, src: &BWImage, x: i64, y: i64, op: RasterOp) -> BWImage {
+        let mut img = self.clone();
+        img.blit(src, x, y, op);
+        img
+    }
+}
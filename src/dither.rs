@@ -0,0 +1,229 @@
+use crate::{img::pack_bw_data, threshold::Binarize, BWImageSize};
+
+/// Error diffusion kernels, the error of a pixel is spread to its unprocessed neighbours
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiffusionKernel {
+    FloydSteinberg,
+    /// Only diffuses 3/4 of the error, keeps more contrast
+    Atkinson,
+    JarvisJudiceNinke,
+    Stucki,
+    Sierra,
+}
+
+impl DiffusionKernel {
+    /// Get the `(dx, dy, weight)` entries and the divisor of the kernel
+    pub fn weights(&self) -> (&'static [(i32, i32, i32)], i32) {
+        match self {
+            Self::FloydSteinberg => (&[(1, 0, 7), (-1, 1, 3), (0, 1, 5), (1, 1, 1)], 16),
+            Self::Atkinson => (
+                &[
+                    (1, 0, 1),
+                    (2, 0, 1),
+                    (-1, 1, 1),
+                    (0, 1, 1),
+                    (1, 1, 1),
+                    (0, 2, 1),
+                ],
+                8,
+            ),
+            Self::JarvisJudiceNinke => (
+                &[
+                    (1, 0, 7),
+                    (2, 0, 5),
+                    (-2, 1, 3),
+                    (-1, 1, 5),
+                    (0, 1, 7),
+                    (1, 1, 5),
+                    (2, 1, 3),
+                    (-2, 2, 1),
+                    (-1, 2, 3),
+                    (0, 2, 5),
+                    (1, 2, 3),
+                    (2, 2, 1),
+                ],
+                48,
+            ),
+            Self::Stucki => (
+                &[
+                    (1, 0, 8),
+                    (2, 0, 4),
+                    (-2, 1, 2),
+                    (-1, 1, 4),
+                    (0, 1, 8),
+                    (1, 1, 4),
+                    (2, 1, 2),
+                    (-2, 2, 1),
+                    (-1, 2, 2),
+                    (0, 2, 4),
+                    (1, 2, 2),
+                    (2, 2, 1),
+                ],
+                42,
+            ),
+            Self::Sierra => (
+                &[
+                    (1, 0, 5),
+                    (2, 0, 3),
+                    (-2, 1, 2),
+                    (-1, 1, 4),
+                    (0, 1, 5),
+                    (1, 1, 4),
+                    (2, 1, 2),
+                    (-1, 2, 2),
+                    (0, 2, 3),
+                    (1, 2, 2),
+                ],
+                32,
+            ),
+        }
+    }
+}
+
+/// Error diffusion dithering, usable with `ImageData::binarize_with`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ErrorDiffusion {
+    pub kernel: DiffusionKernel,
+    /// Process odd rows from right to left, avoids the directional artifacts
+    pub serpentine: bool,
+}
+
+impl ErrorDiffusion {
+    pub fn new(kernel: DiffusionKernel) -> Self {
+        Self {
+            kernel,
+            serpentine: false,
+        }
+    }
+
+    pub fn serpentine(kernel: DiffusionKernel) -> Self {
+        Self {
+            kernel,
+            serpentine: true,
+        }
+    }
+}
+
+impl Default for ErrorDiffusion {
+    fn default() -> Self {
+        Self::new(DiffusionKernel::FloydSteinberg)
+    }
+}
+
+impl Binarize for ErrorDiffusion {
+    fn binarize(&self, gray: &[u8], size: BWImageSize) -> Vec<u8> {
+        let (width, height) = (size.width as usize, size.height as usize);
+        let (weights, divisor) = self.kernel.weights();
+        let mut values: Vec<f32> = gray.iter().map(|v| *v as f32).collect();
+        let mut white = vec![false; values.len()];
+
+        for y in 0..height {
+            let reverse = self.serpentine && y % 2 == 1;
+            for i in 0..width {
+                let x = if reverse { width - 1 - i } else { i };
+                let idx = y * width + x;
+                let value = values[idx];
+                white[idx] = value > 127.5;
+                let error = value - if white[idx] { 255.0 } else { 0.0 };
+
+                for &(dx, dy, weight) in weights {
+                    let dx = if reverse { -dx } else { dx } as i64;
+                    let (nx, ny) = (x as i64 + dx, y + dy as usize);
+                    if nx >= 0 && (nx as usize) < width && ny < height {
+                        values[ny * width + nx as usize] += error * weight as f32 / divisor as f32;
+                    }
+                }
+            }
+        }
+
+        pack_bw_data(size, |x, y| white[y as usize * width + x as usize])
+    }
+}
+
+/// Thresholds tiled over the image for ordered dithering
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ThresholdMap {
+    width: u32,
+    height: u32,
+    values: Vec<u8>,
+}
+
+impl ThresholdMap {
+    /// Create a map from row by row thresholds, e.g. a blue-noise texture.
+    /// Returns `None` if the size doesn't match the values
+    pub fn new(width: u32, height: u32, values: Vec<u8>) -> Option<Self> {
+        if width == 0 || height == 0 || values.len() != width as usize * height as usize {
+            return None;
+        }
+        Some(Self {
+            width,
+            height,
+            values,
+        })
+    }
+
+    /// Bayer matrix of `size`x`size`, size should be 2, 4, 8 or 16
+    pub fn bayer(size: u32) -> Option<Self> {
+        if !matches!(size, 2 | 4 | 8 | 16) {
+            return None;
+        }
+
+        let mut matrix = vec![0u32];
+        let mut n = 1;
+        while n < size {
+            let mut next = vec![0u32; (n * n * 4) as usize];
+            for y in 0..n {
+                for x in 0..n {
+                    let v = matrix[(y * n + x) as usize] * 4;
+                    for (dx, dy, offset) in [(0, 0, 0), (n, 0, 2), (0, n, 3), (n, n, 1)] {
+                        next[((y + dy) * n * 2 + x + dx) as usize] = v + offset;
+                    }
+                }
+            }
+            matrix = next;
+            n *= 2;
+        }
+
+        let levels = (size * size) as f32;
+        Self::new(
+            size,
+            size,
+            matrix
+                .into_iter()
+                .map(|v| ((v as f32 + 0.5) * 256.0 / levels) as u8)
+                .collect(),
+        )
+    }
+
+    #[inline(always)]
+    pub fn get(&self, x: u32, y: u32) -> u8 {
+        self.values[((y % self.height) * self.width + x % self.width) as usize]
+    }
+}
+
+/// Ordered dithering, every pixel is compared with the threshold map tiled at its position.
+/// The result doesn't depend on the neighbours, so it stays stable between video frames
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OrderedDither {
+    pub map: ThresholdMap,
+}
+
+impl OrderedDither {
+    pub fn new(map: ThresholdMap) -> Self {
+        Self { map }
+    }
+
+    /// Ordered dithering with a `size`x`size` Bayer matrix, see `ThresholdMap::bayer`
+    pub fn bayer(size: u32) -> Option<Self> {
+        ThresholdMap::bayer(size).map(Self::new)
+    }
+}
+
+impl Binarize for OrderedDither {
+    fn binarize(&self, gray: &[u8], size: BWImageSize) -> Vec<u8> {
+        let width = size.width as usize;
+        pack_bw_data(size, |x, y| {
+            gray[y as usize * width + x as usize] > self.map.get(x, y)
+        })
+    }
+}
@@ -0,0 +1,332 @@
+use crate::{
+    compose::RasterOp,
+    img::{fill_bits, invert_bits, BWImageSize},
+    BWImage,
+};
+
+/// How the drawn pixels are painted
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Paint {
+    Black,
+    White,
+    /// Invert the pixels under the shape(XOR), drawing twice restores the image
+    Invert,
+}
+
+/// Plots the pixels of a shape, the pixels out of the image are clipped.
+/// With `Paint::Invert` a pixel is inverted every time it is plotted
+struct Plotter<'a> {
+    img: &'a mut BWImage,
+    paint: Paint,
+}
+
+impl Plotter<'_> {
+    #[inline(always)]
+    fn plot(&mut self, x: i64, y: i64) {
+        if let (Ok(x), Ok(y)) = (u32::try_from(x), u32::try_from(y)) {
+            match self.paint {
+                Paint::Invert => {
+                    if let Some(white) = self.img.get_checked(x, y) {
+                        self.img.set(x, y, !white);
+                    }
+                }
+                paint => {
+                    self.img.set_checked(x, y, paint == Paint::White);
+                }
+            }
+        }
+    }
+
+    /// Fill the pixels from `x0` to `x1` of the row, both inclusive
+    fn hline(&mut self, x0: i64, x1: i64, y: i64) {
+        let (x0, x1) = (
+            x0.min(x1).max(0),
+            x0.max(x1).min(self.img.size.width as i64 - 1),
+        );
+        if y < 0 || y >= self.img.size.height as i64 || x0 > x1 {
+            return;
+        }
+        let row_len = self.img.size.get_padded_row_len();
+        let row = &mut self.img.pixels[y as usize * row_len..(y as usize + 1) * row_len];
+        match self.paint {
+            Paint::Invert => invert_bits(row, x0 as usize, x1 as usize + 1),
+            paint => fill_bits(row, x0 as usize, x1 as usize + 1, paint == Paint::White),
+        }
+    }
+
+    /// Clip the line to the image with the Liang–Barsky algorithm, `None` if it misses the image.
+    /// The clipped ends are rounded to the nearest pixels
+    fn clip_line(
+        &self,
+        (x0, y0): (i64, i64),
+        (x1, y1): (i64, i64),
+    ) -> Option<((i64, i64), (i64, i64))> {
+        let (width, height) = (self.img.size.width as i64, self.img.size.height as i64);
+        let (dx, dy) = ((x1 - x0) as f64, (y1 - y0) as f64);
+        let (mut t0, mut t1) = (0f64, 1f64);
+        for (p, q) in [
+            (-dx, x0),
+            (dx, width - 1 - x0),
+            (-dy, y0),
+            (dy, height - 1 - y0),
+        ] {
+            if p == 0.0 {
+                if q < 0 {
+                    return None;
+                }
+            } else if p < 0.0 {
+                t0 = t0.max(q as f64 / p);
+            } else {
+                t1 = t1.min(q as f64 / p);
+            }
+        }
+        if t0 > t1 {
+            return None;
+        }
+        let at = |t: f64| (x0 + (t * dx).round() as i64, y0 + (t * dy).round() as i64);
+        Some((at(t0), at(t1)))
+    }
+
+    fn line(&mut self, from: (i64, i64), to: (i64, i64)) {
+        let Some(((x0, y0), (x1, y1))) = self.clip_line(from, to) else {
+            return;
+        };
+        let (dx, dy) = ((x1 - x0).abs(), -(y1 - y0).abs());
+        let (sx, sy) = ((x1 - x0).signum(), (y1 - y0).signum());
+        let (mut x, mut y, mut err) = (x0, y0, dx + dy);
+        loop {
+            self.plot(x, y);
+            if x == x1 && y == y1 {
+                break;
+            }
+            let e2 = 2 * err;
+            if e2 >= dy {
+                err += dy;
+                x += sx;
+            }
+            if e2 <= dx {
+                err += dx;
+                y += sy;
+            }
+        }
+    }
+
+    /// Plot the outline or fill the rows inside an ellipse, a pixel is inside if
+    /// `dx²/rx² + dy²/ry² <= 1`. Only the rows of the image are visited
+    fn ellipse(&mut self, (cx, cy): (i64, i64), rx: i64, ry: i64, fill: bool) {
+        let (width, height) = (self.img.size.width as i64, self.img.size.height as i64);
+        if cx + rx < 0 || cx - rx >= width || cy + ry < 0 || cy - ry >= height {
+            return;
+        }
+        let (rx2, ry2) = ((rx as u128).pow(2), (ry as u128).pow(2));
+        // half width of the row `dy` away from the center, -1 past the ellipse
+        let half_width = |dy: i64| -> i64 {
+            if dy > ry {
+                -1
+            } else if ry == 0 {
+                rx
+            } else {
+                // rx² · (ry² - dy²) < 2^128 for radii up to u32::MAX
+                ((rx2 * (ry2 - (dy as u128).pow(2))).isqrt() / ry as u128) as i64
+            }
+        };
+
+        for y in (cy - ry).max(0)..=(cy + ry).min(height - 1) {
+            let dy = (y - cy).abs();
+            let outer = half_width(dy);
+            if fill {
+                self.hline(cx - outer, cx + outer, y);
+                continue;
+            }
+            // the outline joins the end of this row to the end of the next row outwards
+            let inner = (half_width(dy + 1) + 1).min(outer);
+            if inner == 0 {
+                self.hline(cx - outer, cx + outer, y);
+            } else {
+                self.hline(cx - outer, cx - inner, y);
+                self.hline(cx + inner, cx + outer, y);
+            }
+        }
+    }
+}
+
+#[inline(always)]
+fn point((x, y): (i32, i32)) -> (i64, i64) {
+    (x as i64, y as i64)
+}
+
+impl BWImage {
+    /// Draw with the paint, `draw` must plot every pixel of the shape once
+    fn paint(&mut self, paint: Paint, draw: impl FnOnce(&mut Plotter)) {
+        draw(&mut Plotter { img: self, paint })
+    }
+
+    /// Draw lines through the points, which can plot a pixel more than once.
+    /// With `Paint::Invert` they are drawn on a mask covering the points in the image,
+    /// which is then inverted into the image, so every covered pixel is inverted once
+    fn paint_path(
+        &mut self,
+        points: &[(i32, i32)],
+        paint: Paint,
+        draw: impl FnOnce(&mut Plotter, &[(i64, i64)]),
+    ) {
+        let mut points: Vec<_> = points.iter().copied().map(point).collect();
+        if paint != Paint::Invert {
+            return draw(&mut Plotter { img: self, paint }, &points);
+        }
+
+        let (Some(x0), Some(x1), Some(y0), Some(y1)) = (
+            points.iter().map(|p| p.0).min(),
+            points.iter().map(|p| p.0).max(),
+            points.iter().map(|p| p.1).min(),
+            points.iter().map(|p| p.1).max(),
+        ) else {
+            return;
+        };
+        let (x0, y0) = (x0.max(0), y0.max(0));
+        let (x1, y1) = (
+            x1.min(self.size.width as i64 - 1),
+            y1.min(self.size.height as i64 - 1),
+        );
+        if x0 > x1 || y0 > y1 {
+            return;
+        }
+
+        for p in &mut points {
+            *p = (p.0 - x0, p.1 - y0);
+        }
+        let size = BWImageSize {
+            width: (x1 - x0 + 1) as u32,
+            height: (y1 - y0 + 1) as u32,
+        };
+        let mut mask = BWImage::new(size, false);
+        draw(
+            &mut Plotter {
+                img: &mut mask,
+                paint: Paint::White,
+            },
+            &points,
+        );
+        self.blit(&mask, x0, y0, RasterOp::Xor);
+    }
+
+    pub fn draw_pixel(&mut self, at: (i32, i32), paint: Paint) {
+        self.paint(paint, |p| p.plot(at.0 as i64, at.1 as i64));
+    }
+
+    /// Draw a line with Bresenham's algorithm, both ends are included.
+    /// Lines leaving the image are clipped to it before drawing
+    pub fn draw_line(&mut self, from: (i32, i32), to: (i32, i32), paint: Paint) {
+        self.paint(paint, |p| p.line(point(from), point(to)));
+    }
+
+    /// Draw connected lines through the points
+    pub fn draw_polyline(&mut self, points: &[(i32, i32)], paint: Paint) {
+        self.paint_path(points, paint, |p, points| {
+            for pair in points.windows(2) {
+                p.line(pair[0], pair[1]);
+            }
+            if let [single] = points {
+                p.plot(single.0, single.1);
+            }
+        });
+    }
+
+    /// Draw the outline of a polygon, the last point is connected to the first one
+    pub fn draw_polygon(&mut self, points: &[(i32, i32)], paint: Paint) {
+        self.paint_path(points, paint, |p, points| {
+            for (i, from) in points.iter().enumerate() {
+                p.line(*from, points[(i + 1) % points.len()]);
+            }
+        });
+    }
+
+    /// Fill a polygon with the even-odd rule, a pixel is inside if its center is
+    pub fn fill_polygon(&mut self, points: &[(i32, i32)], paint: Paint) {
+        if points.len() < 3 {
+            return;
+        }
+        let min_y = (points.iter().map(|p| p.1).min().unwrap() as i64).max(0);
+        let max_y = (points.iter().map(|p| p.1).max().unwrap() as i64).min(self.size.height as i64);
+
+        self.paint(paint, |p| {
+            let mut crossings = vec![];
+            for y in min_y..max_y {
+                let center = y as f64 + 0.5;
+                crossings.clear();
+                for (i, &(x0, y0)) in points.iter().enumerate() {
+                    let (x1, y1) = points[(i + 1) % points.len()];
+                    let (y0, y1) = (y0 as f64, y1 as f64);
+                    if (y0 <= center) != (y1 <= center) {
+                        let t = (center - y0) / (y1 - y0);
+                        crossings.push(x0 as f64 + t * (x1 as f64 - x0 as f64));
+                    }
+                }
+                crossings.sort_by(|a, b| a.total_cmp(b));
+                for pair in crossings.chunks_exact(2) {
+                    let (from, to) = ((pair[0] - 0.5).ceil(), (pair[1] - 0.5).ceil());
+                    if from < to {
+                        p.hline(from as i64, to as i64 - 1, y);
+                    }
+                }
+            }
+        });
+    }
+
+    /// Draw the outline of a rectangle with the top left corner at `(x, y)`
+    pub fn draw_rect(&mut self, (x, y): (i32, i32), width: u32, height: u32, paint: Paint) {
+        if width == 0 || height == 0 {
+            return;
+        }
+        let (x0, y0) = point((x, y));
+        let (x1, y1) = (x0 + width as i64 - 1, y0 + height as i64 - 1);
+        self.paint(paint, |p| {
+            p.hline(x0, x1, y0);
+            if y1 != y0 {
+                p.hline(x0, x1, y1);
+            }
+            for y in (y0 + 1).max(0)..y1.min(p.img.size.height as i64) {
+                p.plot(x0, y);
+                if x1 != x0 {
+                    p.plot(x1, y);
+                }
+            }
+        });
+    }
+
+    /// Fill a rectangle with the top left corner at `(x, y)`
+    pub fn fill_rect(&mut self, (x, y): (i32, i32), width: u32, height: u32, paint: Paint) {
+        if width == 0 {
+            return;
+        }
+        let (x0, y0) = point((x, y));
+        let rows = y0.max(0)..(y0 + height as i64).min(self.size.height as i64);
+        self.paint(paint, |p| {
+            for y in rows {
+                p.hline(x0, x0 + width as i64 - 1, y);
+            }
+        });
+    }
+
+    pub fn draw_circle(&mut self, center: (i32, i32), radius: u32, paint: Paint) {
+        self.draw_ellipse(center, radius, radius, paint);
+    }
+
+    pub fn fill_circle(&mut self, center: (i32, i32), radius: u32, paint: Paint) {
+        self.fill_ellipse(center, radius, radius, paint);
+    }
+
+    /// Draw the outline of an axis aligned ellipse with the radii `rx` and `ry`
+    pub fn draw_ellipse(&mut self, center: (i32, i32), rx: u32, ry: u32, paint: Paint) {
+        self.paint(paint, |p| {
+            p.ellipse(point(center), rx as i64, ry as i64, false)
+        });
+    }
+
+    /// Fill an axis aligned ellipse with the radii `rx` and `ry`
+    pub fn fill_ellipse(&mut self, center: (i32, i32), rx: u32, ry: u32, paint: Paint) {
+        self.paint(paint, |p| {
+            p.ellipse(point(center), rx as i64, ry as i64, true)
+        });
+    }
+}
@@ -126,7 +126,7 @@ pub mod compress {
 
 #[cfg(feature = "video")]
 pub mod video {
-    use crate::{BWDataErr, BWImage, RgbData, VideoError};
+    use crate::{threshold::Binarize, BWDataErr, BWImage, ImageData, RgbData, VideoError};
     use ffmpeg_next::{
         codec::{self, packet::packet::Packet},
         decoder,
@@ -145,6 +145,7 @@ pub mod video {
         decoder: decoder::Video,
         scaler: scaling::Context,
         packets: LinkedList<Packet>,
+        binarizer: Option<Box<dyn Binarize>>,
     }
 
     impl VideoIter {
@@ -200,9 +201,16 @@ pub mod video {
                 duration: secs as u64,
                 frame_rate: frame_rate as u32,
                 packets,
+                binarizer: None,
             })
         }
 
+        /// Binarize the frames with another strategy instead of the fixed threshold,
+        /// e.g. `dither::OrderedDither` for temporally stable output
+        pub fn set_binarizer<B: Binarize + 'static>(&mut self, binarizer: B) {
+            self.binarizer = Some(Box::new(binarizer));
+        }
+
         pub fn convert(&mut self, packet: Packet) -> crate::Result<(Vec<BWImage>, u64)> {
             self.decoder
                 .send_packet(&packet)
@@ -220,7 +228,13 @@ pub mod video {
                 let (width, height) = (self.scaler.output().width, self.scaler.output().height);
                 let data = scalled.data(0);
 
-                let img = match BWImage::parse(&RgbData::new(data, width, height)) {
+                // rows of ffmpeg frames may be padded, linesize is usually larger than width * 3
+                let rgb = RgbData::with_stride(data, width, height, scalled.stride(0));
+                let parsed = match &self.binarizer {
+                    Some(binarizer) => rgb.binarize_with(binarizer).parse_bw_image(),
+                    None => rgb.parse_bw_image(),
+                };
+                let img = match parsed {
                     Ok(r) => r,
                     Err(e) => {
                         if let BWDataErr::WrongSize(_, _, _) = e {
@@ -0,0 +1,830 @@
+use std::{collections::HashMap, sync::OnceLock};
+
+use crate::{compose::RasterOp, draw::Paint, img::BWImageSize, BWError, BWImage};
+
+/// Bitmap of a character, the white pixels are the ink
+#[derive(Clone, Debug)]
+pub struct Glyph {
+    pub bitmap: BWImage,
+    /// Position of the bitmap from the top left of the character cell
+    pub offset: (i32, i32),
+    /// Horizontal distance to the next character
+    pub advance: u32,
+}
+
+/// Font of bitmap glyphs, e.g. loaded from BDF or PSF files
+#[derive(Clone, Debug)]
+pub struct BitmapFont {
+    /// Distance from the top of the line to the baseline
+    pub ascent: u32,
+    /// Distance from the baseline to the bottom of the line
+    pub descent: u32,
+    /// Character drawn for the characters missing in the font
+    pub fallback: Option<char>,
+    glyphs: HashMap<char, Glyph>,
+}
+
+impl BitmapFont {
+    pub fn new(ascent: u32, descent: u32) -> Self {
+        Self {
+            ascent,
+            descent,
+            fallback: None,
+            glyphs: HashMap::new(),
+        }
+    }
+
+    pub fn insert(&mut self, c: char, glyph: Glyph) {
+        self.glyphs.insert(c, glyph);
+    }
+
+    /// Get the glyph of the character, or the fallback glyph if it's missing
+    pub fn glyph(&self, c: char) -> Option<&Glyph> {
+        self.glyphs
+            .get(&c)
+            .or_else(|| self.fallback.and_then(|c| self.glyphs.get(&c)))
+    }
+
+    #[inline(always)]
+    pub fn line_height(&self) -> u32 {
+        self.ascent + self.descent
+    }
+
+    /// Built-in 5x7 font of the printable ascii characters, from the public domain X11 fixed fonts
+    pub fn font_5x7() -> &'static BitmapFont {
+        static FONT: OnceLock<BitmapFont> = OnceLock::new();
+        FONT.get_or_init(|| Self::ascii_cells(5, 6, 1, FONT_5X7.iter().map(|g| &g[..])))
+    }
+
+    /// Built-in 8x13 font of the printable ascii characters, from the public domain X11 fixed fonts
+    pub fn font_8x13() -> &'static BitmapFont {
+        static FONT: OnceLock<BitmapFont> = OnceLock::new();
+        FONT.get_or_init(|| Self::ascii_cells(8, 11, 2, FONT_8X13.iter().map(|g| &g[..])))
+    }
+
+    /// Monospace font of the characters from `' '` to `'~'`, one byte per row
+    fn ascii_cells<'a>(
+        width: u32,
+        ascent: u32,
+        descent: u32,
+        glyphs: impl Iterator<Item = &'a [u8]>,
+    ) -> Self {
+        let mut font = Self::new(ascent, descent);
+        let mask = (0xff00u16 >> width) as u8;
+        for (c, rows) in (' '..='~').zip(glyphs) {
+            font.insert(
+                c,
+                Glyph {
+                    bitmap: BWImage {
+                        size: BWImageSize {
+                            width,
+                            height: rows.len() as u32,
+                        },
+                        pixels: rows.iter().map(|row| row & mask).collect(),
+                    },
+                    offset: (0, 0),
+                    advance: width,
+                },
+            );
+        }
+        font.fallback = Some('?');
+        font
+    }
+
+    /// Parse a font in the Glyph Bitmap Distribution Format, the encodings are used as unicode
+    pub fn parse_bdf(data: &str) -> crate::Result<Self> {
+        let err = |msg: String| BWError::Font(format!("bdf: {msg}"));
+        let number = |value: Option<&str>, line: &str| {
+            value
+                .and_then(|v| v.parse::<i32>().ok())
+                .ok_or_else(|| err(format!("invalid line: {line}")))
+        };
+
+        let (mut ascent, mut descent, mut bounding_box) = (None, None, None);
+        let mut font = Self::new(0, 0);
+        let mut lines = data.lines();
+        while let Some(line) = lines.next() {
+            let mut words = line.split_whitespace();
+            match words.next() {
+                Some("FONTBOUNDINGBOX") => {
+                    let height = number(words.nth(1), line)?;
+                    let y = number(words.nth(1), line)?;
+                    // the ascent and descent used when they are missing
+                    bounding_box = Some(
+                        height
+                            .checked_add(y)
+                            .zip(y.checked_neg())
+                            .ok_or_else(|| err(format!("invalid line: {line}")))?,
+                    );
+                }
+                Some("FONT_ASCENT") => ascent = Some(number(words.next(), line)?),
+                Some("FONT_DESCENT") => descent = Some(number(words.next(), line)?),
+                Some("DEFAULT_CHAR") => {
+                    font.fallback = char::from_u32(number(words.next(), line)? as u32)
+                }
+                Some("STARTCHAR") => {
+                    let (mut encoding, mut advance, mut bbx) = (-1, 0, None);
+                    let mut rows = vec![];
+                    for line in lines.by_ref() {
+                        let mut words = line.split_whitespace();
+                        match words.next() {
+                            Some("ENCODING") => encoding = number(words.next(), line)?,
+                            Some("DWIDTH") => advance = number(words.next(), line)?,
+                            Some("BBX") => {
+                                let values = words
+                                    .map(|w| number(Some(w), line))
+                                    .collect::<Result<Vec<_>, _>>()?;
+                                if values.len() != 4 || values[0] < 0 || values[1] < 0 {
+                                    return Err(err(format!("invalid line: {line}")));
+                                }
+                                bbx = Some((values[0], values[1], values[2], values[3]));
+                            }
+                            Some("ENDCHAR") => break,
+                            Some(hex) if bbx.is_some() && hex != "BITMAP" => rows.push(hex),
+                            _ => {}
+                        }
+                    }
+
+                    let Some((width, height, x, y)) = bbx else {
+                        return Err(err("character without BBX".into()));
+                    };
+                    let Some(c) = u32::try_from(encoding).ok().and_then(char::from_u32) else {
+                        continue;
+                    };
+                    let ascent = ascent
+                        .or(bounding_box.map(|(ascent, _)| ascent))
+                        .ok_or_else(|| err("missing FONT_ASCENT".into()))?;
+                    let top = ascent
+                        .checked_sub(y)
+                        .and_then(|top| top.checked_sub(height))
+                        .ok_or_else(|| err(format!("BBX of {c:?} is out of range")))?;
+
+                    // the BBX must be covered by the rows before allocating the bitmap
+                    let row_len = (width as usize).div_ceil(8);
+                    if rows.len() < height as usize
+                        || rows.iter().any(|row| row.len() < row_len * 2)
+                    {
+                        return Err(err(format!("bitmap of {c:?} is smaller than its BBX")));
+                    }
+                    let size = BWImageSize {
+                        width: width as u32,
+                        height: height as u32,
+                    };
+                    let mut bitmap = BWImage::new(size, false);
+                    for (i, row) in rows.iter().take(height as usize).enumerate() {
+                        for j in 0..row_len {
+                            let byte = row
+                                .get(j * 2..j * 2 + 2)
+                                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                                .ok_or_else(|| err(format!("invalid bitmap row: {row}")))?;
+                            bitmap.pixels[i * row_len + j] = byte;
+                        }
+                    }
+                    // clear the padding bits
+                    bitmap = bitmap.crop(0, 0, size.width, size.height);
+
+                    font.insert(
+                        c,
+                        Glyph {
+                            bitmap,
+                            offset: (x, top),
+                            advance: advance.max(0) as u32,
+                        },
+                    );
+                }
+                _ => {}
+            }
+        }
+
+        font.ascent = ascent
+            .or(bounding_box.map(|(ascent, _)| ascent))
+            .ok_or_else(|| err("missing FONT_ASCENT".into()))?
+            .max(0) as u32;
+        font.descent = descent
+            .or(bounding_box.map(|(_, descent)| descent))
+            .unwrap_or(0)
+            .max(0) as u32;
+        Ok(font)
+    }
+
+    /// Parse a PC Screen Font(version 1 or 2). Without a unicode table,
+    /// the index of the glyph is used as the character
+    pub fn parse_psf(data: &[u8]) -> crate::Result<Self> {
+        let err = |msg: &str| BWError::Font(format!("psf: {msg}"));
+        let u32_at = |pos: usize| {
+            data.get(pos..pos + 4)
+                .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]) as usize)
+                .ok_or_else(|| err("truncated header"))
+        };
+
+        let (offset, count, glyph_len, width, height, has_table, version) = match data {
+            [0x36, 0x04, mode, charsize, ..] => (
+                4,
+                if mode & 0x01 != 0 { 512 } else { 256 },
+                *charsize as usize,
+                8,
+                *charsize as usize,
+                mode & 0x06 != 0,
+                1,
+            ),
+            [0x72, 0xb5, 0x4a, 0x86, ..] => (
+                u32_at(8)?,
+                u32_at(16)?,
+                u32_at(20)?,
+                u32_at(28)?,
+                u32_at(24)?,
+                u32_at(12)? & 0x01 != 0,
+                2,
+            ),
+            _ => return Err(err("invalid magic number")),
+        };
+        let size = BWImageSize {
+            width: width as u32,
+            height: height as u32,
+        };
+        if glyph_len == 0 {
+            return Err(err("empty glyphs"));
+        }
+        if glyph_len < size.get_padded_bytes_len() as usize {
+            return Err(err("glyph size doesn't match the width and height"));
+        }
+        // the glyphs must be in the data before allocating anything for them
+        let glyphs = count
+            .checked_mul(glyph_len)
+            .and_then(|len| len.checked_add(offset))
+            .and_then(|end| data.get(offset..end))
+            .ok_or_else(|| err("truncated glyphs"))?;
+        let table_start = offset + glyphs.len();
+
+        // characters of every glyph
+        let mut chars: Vec<Vec<char>> = vec![vec![]; count];
+        if has_table {
+            let mut table = &data[table_start..];
+            for glyph_chars in chars.iter_mut() {
+                if version == 1 {
+                    let mut sequence = false;
+                    while let [lo, hi, rest @ ..] = table {
+                        table = rest;
+                        match u16::from_le_bytes([*lo, *hi]) {
+                            0xffff => break,
+                            0xfffe => sequence = true,
+                            c if !sequence => glyph_chars.extend(char::from_u32(c as u32)),
+                            _ => {}
+                        }
+                    }
+                } else {
+                    let end = table.iter().position(|b| *b == 0xff).unwrap_or(table.len());
+                    let entry = &table[..end];
+                    let singles = entry.split(|b| *b == 0xfe).next().unwrap_or_default();
+                    glyph_chars.extend(String::from_utf8_lossy(singles).chars());
+                    table = table.get(end + 1..).unwrap_or_default();
+                }
+            }
+        } else {
+            for (i, glyph_chars) in chars.iter_mut().enumerate() {
+                glyph_chars.extend(char::from_u32(i as u32));
+            }
+        }
+
+        let mut font = Self::new(height as u32, 0);
+        for (glyph, glyph_chars) in glyphs.chunks_exact(glyph_len).zip(chars) {
+            let bitmap = BWImage {
+                size,
+                pixels: glyph[..size.get_padded_bytes_len() as usize].to_vec(),
+            }
+            .crop(0, 0, size.width, size.height);
+            for c in glyph_chars {
+                font.insert(
+                    c,
+                    Glyph {
+                        bitmap: bitmap.clone(),
+                        offset: (0, 0),
+                        advance: width as u32,
+                    },
+                );
+            }
+        }
+        if font.glyphs.contains_key(&'?') {
+            font.fallback = Some('?');
+        }
+        Ok(font)
+    }
+
+    /// Get the width of a single line of text
+    pub fn text_width(&self, line: &str) -> u32 {
+        line.chars()
+            .filter_map(|c| self.glyph(c))
+            .map(|g| g.advance)
+            .sum()
+    }
+
+    /// Split the text into lines no wider than `max_width`, breaking at spaces if possible
+    pub fn wrap(&self, text: &str, max_width: u32) -> Vec<String> {
+        let mut lines = vec![];
+        for paragraph in text.split('\n') {
+            let mut line = String::new();
+            for word in paragraph.split(' ') {
+                let candidate = if line.is_empty() {
+                    word.to_string()
+                } else {
+                    format!("{line} {word}")
+                };
+                if self.text_width(&candidate) <= max_width {
+                    line = candidate;
+                    continue;
+                }
+
+                if !line.is_empty() {
+                    lines.push(std::mem::take(&mut line));
+                }
+                // break the words longer than a line
+                for c in word.chars() {
+                    line.push(c);
+                    if self.text_width(&line) > max_width && line.chars().count() > 1 {
+                        line.pop();
+                        lines.push(std::mem::replace(&mut line, c.to_string()));
+                    }
+                }
+            }
+            lines.push(line);
+        }
+        lines
+    }
+}
+
+/// Horizontal alignment of the lines of a text
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TextAlign {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct TextStyle<'a> {
+    pub font: &'a BitmapFont,
+    pub paint: Paint,
+    pub align: TextAlign,
+    /// Extra pixels between the lines
+    pub line_spacing: u32,
+    /// Wrap the lines wider than this, the lines are aligned in this width if set
+    pub max_width: Option<u32>,
+}
+
+impl<'a> TextStyle<'a> {
+    pub fn new(font: &'a BitmapFont, paint: Paint) -> Self {
+        Self {
+            font,
+            paint,
+            align: TextAlign::default(),
+            line_spacing: 0,
+            max_width: None,
+        }
+    }
+
+    /// Split the text into the lines to draw
+    pub fn lines(&self, text: &str) -> Vec<String> {
+        match self.max_width {
+            Some(width) => self.font.wrap(text, width),
+            None => text.split('\n').map(String::from).collect(),
+        }
+    }
+
+    /// Get the size of the area the text is drawn in
+    pub fn measure(&self, text: &str) -> BWImageSize {
+        let lines = self.lines(text);
+        let width = self.max_width.unwrap_or_else(|| {
+            lines
+                .iter()
+                .map(|l| self.font.text_width(l))
+                .max()
+                .unwrap_or(0)
+        });
+        let count = lines.len() as u32;
+        BWImageSize {
+            width,
+            height: count * self.font.line_height() + count.saturating_sub(1) * self.line_spacing,
+        }
+    }
+}
+
+impl BWImage {
+    /// Draw the text with the top left corner of its area at `at`, returns the size of the area
+    pub fn draw_text(&mut self, text: &str, at: (i32, i32), style: &TextStyle) -> BWImageSize {
+        let area = style.measure(text);
+        let op = match style.paint {
+            Paint::White => RasterOp::Or,
+            Paint::Black => RasterOp::AndNot,
+            Paint::Invert => RasterOp::Xor,
+        };
+
+        let mut y = at.1 as i64;
+        for line in style.lines(text) {
+            let free = area.width.saturating_sub(style.font.text_width(&line)) as i64;
+            let mut x = at.0 as i64
+                + match style.align {
+                    TextAlign::Left => 0,
+                    TextAlign::Center => free / 2,
+                    TextAlign::Right => free,
+                };
+            for glyph in line.chars().filter_map(|c| style.font.glyph(c)) {
+                self.blit(
+                    &glyph.bitmap,
+                    x + glyph.offset.0 as i64,
+                    y + glyph.offset.1 as i64,
+                    op,
+                );
+                x += glyph.advance as i64;
+            }
+            y += (style.font.line_height() + style.line_spacing) as i64;
+        }
+        area
+    }
+}
+
+const FONT_5X7: [[u8; 7]; 95] = [
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // ' '
+    [0x20, 0x20, 0x20, 0x20, 0x00, 0x20, 0x00], // '!'
+    [0x50, 0x50, 0x50, 0x00, 0x00, 0x00, 0x00], // '"'
+    [0x00, 0x50, 0xf8, 0x50, 0xf8, 0x50, 0x00], // '#'
+    [0x00, 0x70, 0xa0, 0x70, 0x28, 0x70, 0x00], // '$'
+    [0x80, 0x90, 0x20, 0x40, 0x90, 0x10, 0x00], // '%'
+    [0x00, 0x40, 0xa0, 0x40, 0xa0, 0x50, 0x00], // '&'
+    [0x20, 0x20, 0x20, 0x00, 0x00, 0x00, 0x00], // "'"
+    [0x20, 0x40, 0x40, 0x40, 0x40, 0x20, 0x00], // '('
+    [0x40, 0x20, 0x20, 0x20, 0x20, 0x40, 0x00], // ')'
+    [0x00, 0x50, 0x20, 0x70, 0x20, 0x50, 0x00], // '*'
+    [0x00, 0x20, 0x20, 0xf8, 0x20, 0x20, 0x00], // '+'
+    [0x00, 0x00, 0x00, 0x00, 0x30, 0x20, 0x40], // ','
+    [0x00, 0x00, 0x00, 0xf0, 0x00, 0x00, 0x00], // '-'
+    [0x00, 0x00, 0x00, 0x00, 0x60, 0x60, 0x00], // '.'
+    [0x00, 0x10, 0x20, 0x40, 0x80, 0x00, 0x00], // '/'
+    [0x20, 0x50, 0x50, 0x50, 0x50, 0x20, 0x00], // '0'
+    [0x20, 0x60, 0x20, 0x20, 0x20, 0x70, 0x00], // '1'
+    [0x60, 0x90, 0x10, 0x20, 0x40, 0xf0, 0x00], // '2'
+    [0xf0, 0x10, 0x60, 0x10, 0x90, 0x60, 0x00], // '3'
+    [0x20, 0x60, 0xa0, 0xf0, 0x20, 0x20, 0x00], // '4'
+    [0xf0, 0x80, 0xe0, 0x10, 0x90, 0x60, 0x00], // '5'
+    [0x60, 0x80, 0xe0, 0x90, 0x90, 0x60, 0x00], // '6'
+    [0xf0, 0x10, 0x20, 0x20, 0x40, 0x40, 0x00], // '7'
+    [0x60, 0x90, 0x60, 0x90, 0x90, 0x60, 0x00], // '8'
+    [0x60, 0x90, 0x90, 0x70, 0x10, 0x60, 0x00], // '9'
+    [0x00, 0x60, 0x60, 0x00, 0x60, 0x60, 0x00], // ':'
+    [0x00, 0x60, 0x60, 0x00, 0x60, 0x40, 0x80], // ';'
+    [0x00, 0x10, 0x20, 0x40, 0x20, 0x10, 0x00], // '<'
+    [0x00, 0x00, 0xf0, 0x00, 0xf0, 0x00, 0x00], // '='
+    [0x00, 0x40, 0x20, 0x10, 0x20, 0x40, 0x00], // '>'
+    [0x20, 0x50, 0x10, 0x20, 0x00, 0x20, 0x00], // '?'
+    [0x60, 0x90, 0xb0, 0xb0, 0x80, 0x60, 0x00], // '@'
+    [0x60, 0x90, 0x90, 0xf0, 0x90, 0x90, 0x00], // 'A'
+    [0xe0, 0x90, 0xe0, 0x90, 0x90, 0xe0, 0x00], // 'B'
+    [0x60, 0x90, 0x80, 0x80, 0x90, 0x60, 0x00], // 'C'
+    [0xe0, 0x90, 0x90, 0x90, 0x90, 0xe0, 0x00], // 'D'
+    [0xf0, 0x80, 0xe0, 0x80, 0x80, 0xf0, 0x00], // 'E'
+    [0xf0, 0x80, 0xe0, 0x80, 0x80, 0x80, 0x00], // 'F'
+    [0x60, 0x90, 0x80, 0xb0, 0x90, 0x70, 0x00], // 'G'
+    [0x90, 0x90, 0xf0, 0x90, 0x90, 0x90, 0x00], // 'H'
+    [0x70, 0x20, 0x20, 0x20, 0x20, 0x70, 0x00], // 'I'
+    [0x10, 0x10, 0x10, 0x10, 0x90, 0x60, 0x00], // 'J'
+    [0x90, 0xa0, 0xc0, 0xc0, 0xa0, 0x90, 0x00], // 'K'
+    [0x80, 0x80, 0x80, 0x80, 0x80, 0xf0, 0x00], // 'L'
+    [0x90, 0xf0, 0xf0, 0x90, 0x90, 0x90, 0x00], // 'M'
+    [0x90, 0xd0, 0xd0, 0xb0, 0xb0, 0x90, 0x00], // 'N'
+    [0x60, 0x90, 0x90, 0x90, 0x90, 0x60, 0x00], // 'O'
+    [0xe0, 0x90, 0x90, 0xe0, 0x80, 0x80, 0x00], // 'P'
+    [0x60, 0x90, 0x90, 0x90, 0xd0, 0x60, 0x10], // 'Q'
+    [0xe0, 0x90, 0x90, 0xe0, 0xa0, 0x90, 0x00], // 'R'
+    [0x60, 0x90, 0x40, 0x20, 0x90, 0x60, 0x00], // 'S'
+    [0x70, 0x20, 0x20, 0x20, 0x20, 0x20, 0x00], // 'T'
+    [0x90, 0x90, 0x90, 0x90, 0x90, 0x60, 0x00], // 'U'
+    [0x90, 0x90, 0x90, 0x90, 0x60, 0x60, 0x00], // 'V'
+    [0x90, 0x90, 0x90, 0xf0, 0xf0, 0x90, 0x00], // 'W'
+    [0x90, 0x90, 0x60, 0x60, 0x90, 0x90, 0x00], // 'X'
+    [0x50, 0x50, 0x50, 0x20, 0x20, 0x20, 0x00], // 'Y'
+    [0xf0, 0x10, 0x20, 0x40, 0x80, 0xf0, 0x00], // 'Z'
+    [0x70, 0x40, 0x40, 0x40, 0x40, 0x70, 0x00], // '['
+    [0x00, 0x80, 0x40, 0x20, 0x10, 0x00, 0x00], // '\\'
+    [0x70, 0x10, 0x10, 0x10, 0x10, 0x70, 0x00], // ']'
+    [0x20, 0x50, 0x00, 0x00, 0x00, 0x00, 0x00], // '^'
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0x00], // '_'
+    [0x40, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00], // '`'
+    [0x00, 0x00, 0x70, 0x90, 0xb0, 0x50, 0x00], // 'a'
+    [0x80, 0x80, 0xe0, 0x90, 0x90, 0xe0, 0x00], // 'b'
+    [0x00, 0x00, 0x60, 0x80, 0x80, 0x60, 0x00], // 'c'
+    [0x10, 0x10, 0x70, 0x90, 0x90, 0x70, 0x00], // 'd'
+    [0x00, 0x00, 0x60, 0xb0, 0xc0, 0x60, 0x00], // 'e'
+    [0x20, 0x50, 0x40, 0xe0, 0x40, 0x40, 0x00], // 'f'
+    [0x00, 0x00, 0x70, 0x90, 0x60, 0x80, 0x70], // 'g'
+    [0x80, 0x80, 0xe0, 0x90, 0x90, 0x90, 0x00], // 'h'
+    [0x20, 0x00, 0x60, 0x20, 0x20, 0x70, 0x00], // 'i'
+    [0x10, 0x00, 0x10, 0x10, 0x10, 0x50, 0x20], // 'j'
+    [0x80, 0x80, 0xa0, 0xc0, 0xa0, 0x90, 0x00], // 'k'
+    [0x60, 0x20, 0x20, 0x20, 0x20, 0x70, 0x00], // 'l'
+    [0x00, 0x00, 0xa0, 0xf0, 0x90, 0x90, 0x00], // 'm'
+    [0x00, 0x00, 0xe0, 0x90, 0x90, 0x90, 0x00], // 'n'
+    [0x00, 0x00, 0x60, 0x90, 0x90, 0x60, 0x00], // 'o'
+    [0x00, 0x00, 0xe0, 0x90, 0x90, 0xe0, 0x80], // 'p'
+    [0x00, 0x00, 0x70, 0x90, 0x90, 0x70, 0x10], // 'q'
+    [0x00, 0x00, 0xe0, 0x90, 0x80, 0x80, 0x00], // 'r'
+    [0x00, 0x00, 0x70, 0xc0, 0x30, 0xe0, 0x00], // 's'
+    [0x40, 0x40, 0xe0, 0x40, 0x40, 0x30, 0x00], // 't'
+    [0x00, 0x00, 0x90, 0x90, 0x90, 0x70, 0x00], // 'u'
+    [0x00, 0x00, 0x50, 0x50, 0x50, 0x20, 0x00], // 'v'
+    [0x00, 0x00, 0x90, 0x90, 0xf0, 0xf0, 0x00], // 'w'
+    [0x00, 0x00, 0x90, 0x60, 0x60, 0x90, 0x00], // 'x'
+    [0x00, 0x00, 0x90, 0x90, 0x50, 0x20, 0x40], // 'y'
+    [0x00, 0x00, 0xf0, 0x20, 0x40, 0xf0, 0x00], // 'z'
+    [0x10, 0x20, 0x60, 0x20, 0x20, 0x10, 0x00], // '{'
+    [0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x00], // '|'
+    [0x40, 0x20, 0x30, 0x20, 0x20, 0x40, 0x00], // '}'
+    [0x50, 0xa0, 0x00, 0x00, 0x00, 0x00, 0x00], // '~'
+];
+
+const FONT_8X13: [[u8; 13]; 95] = [
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ], // ' '
+    [
+        0x00, 0x00, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x00, 0x10, 0x00, 0x00,
+    ], // '!'
+    [
+        0x00, 0x00, 0x24, 0x24, 0x24, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ], // '"'
+    [
+        0x00, 0x00, 0x00, 0x24, 0x24, 0x7e, 0x24, 0x7e, 0x24, 0x24, 0x00, 0x00, 0x00,
+    ], // '#'
+    [
+        0x00, 0x00, 0x10, 0x3c, 0x50, 0x50, 0x38, 0x14, 0x14, 0x78, 0x10, 0x00, 0x00,
+    ], // '$'
+    [
+        0x00, 0x00, 0x22, 0x52, 0x24, 0x08, 0x08, 0x10, 0x24, 0x2a, 0x44, 0x00, 0x00,
+    ], // '%'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x30, 0x48, 0x48, 0x30, 0x4a, 0x44, 0x3a, 0x00, 0x00,
+    ], // '&'
+    [
+        0x00, 0x00, 0x10, 0x10, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ], // "'"
+    [
+        0x00, 0x00, 0x04, 0x08, 0x08, 0x10, 0x10, 0x10, 0x08, 0x08, 0x04, 0x00, 0x00,
+    ], // '('
+    [
+        0x00, 0x00, 0x20, 0x10, 0x10, 0x08, 0x08, 0x08, 0x10, 0x10, 0x20, 0x00, 0x00,
+    ], // ')'
+    [
+        0x00, 0x00, 0x24, 0x18, 0x7e, 0x18, 0x24, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ], // '*'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x10, 0x10, 0x7c, 0x10, 0x10, 0x00, 0x00, 0x00, 0x00,
+    ], // '+'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x38, 0x30, 0x40, 0x00,
+    ], // ','
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ], // '-'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x38, 0x10, 0x00,
+    ], // '.'
+    [
+        0x00, 0x00, 0x02, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80, 0x80, 0x00, 0x00,
+    ], // '/'
+    [
+        0x00, 0x00, 0x18, 0x24, 0x42, 0x42, 0x42, 0x42, 0x42, 0x24, 0x18, 0x00, 0x00,
+    ], // '0'
+    [
+        0x00, 0x00, 0x10, 0x30, 0x50, 0x10, 0x10, 0x10, 0x10, 0x10, 0x7c, 0x00, 0x00,
+    ], // '1'
+    [
+        0x00, 0x00, 0x3c, 0x42, 0x42, 0x02, 0x04, 0x18, 0x20, 0x40, 0x7e, 0x00, 0x00,
+    ], // '2'
+    [
+        0x00, 0x00, 0x7e, 0x02, 0x04, 0x08, 0x1c, 0x02, 0x02, 0x42, 0x3c, 0x00, 0x00,
+    ], // '3'
+    [
+        0x00, 0x00, 0x04, 0x0c, 0x14, 0x24, 0x44, 0x44, 0x7e, 0x04, 0x04, 0x00, 0x00,
+    ], // '4'
+    [
+        0x00, 0x00, 0x7e, 0x40, 0x40, 0x5c, 0x62, 0x02, 0x02, 0x42, 0x3c, 0x00, 0x00,
+    ], // '5'
+    [
+        0x00, 0x00, 0x1c, 0x20, 0x40, 0x40, 0x5c, 0x62, 0x42, 0x42, 0x3c, 0x00, 0x00,
+    ], // '6'
+    [
+        0x00, 0x00, 0x7e, 0x02, 0x04, 0x08, 0x08, 0x10, 0x10, 0x20, 0x20, 0x00, 0x00,
+    ], // '7'
+    [
+        0x00, 0x00, 0x3c, 0x42, 0x42, 0x42, 0x3c, 0x42, 0x42, 0x42, 0x3c, 0x00, 0x00,
+    ], // '8'
+    [
+        0x00, 0x00, 0x3c, 0x42, 0x42, 0x46, 0x3a, 0x02, 0x02, 0x04, 0x38, 0x00, 0x00,
+    ], // '9'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x10, 0x38, 0x10, 0x00, 0x00, 0x10, 0x38, 0x10, 0x00,
+    ], // ':'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x10, 0x38, 0x10, 0x00, 0x00, 0x38, 0x30, 0x40, 0x00,
+    ], // ';'
+    [
+        0x00, 0x00, 0x02, 0x04, 0x08, 0x10, 0x20, 0x10, 0x08, 0x04, 0x02, 0x00, 0x00,
+    ], // '<'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x7e, 0x00, 0x00, 0x7e, 0x00, 0x00, 0x00, 0x00,
+    ], // '='
+    [
+        0x00, 0x00, 0x40, 0x20, 0x10, 0x08, 0x04, 0x08, 0x10, 0x20, 0x40, 0x00, 0x00,
+    ], // '>'
+    [
+        0x00, 0x00, 0x3c, 0x42, 0x42, 0x02, 0x04, 0x08, 0x08, 0x00, 0x08, 0x00, 0x00,
+    ], // '?'
+    [
+        0x00, 0x00, 0x3c, 0x42, 0x42, 0x4e, 0x52, 0x56, 0x4a, 0x40, 0x3c, 0x00, 0x00,
+    ], // '@'
+    [
+        0x00, 0x00, 0x18, 0x24, 0x42, 0x42, 0x42, 0x7e, 0x42, 0x42, 0x42, 0x00, 0x00,
+    ], // 'A'
+    [
+        0x00, 0x00, 0x78, 0x44, 0x42, 0x44, 0x78, 0x44, 0x42, 0x44, 0x78, 0x00, 0x00,
+    ], // 'B'
+    [
+        0x00, 0x00, 0x3c, 0x42, 0x40, 0x40, 0x40, 0x40, 0x40, 0x42, 0x3c, 0x00, 0x00,
+    ], // 'C'
+    [
+        0x00, 0x00, 0x78, 0x44, 0x42, 0x42, 0x42, 0x42, 0x42, 0x44, 0x78, 0x00, 0x00,
+    ], // 'D'
+    [
+        0x00, 0x00, 0x7e, 0x40, 0x40, 0x40, 0x78, 0x40, 0x40, 0x40, 0x7e, 0x00, 0x00,
+    ], // 'E'
+    [
+        0x00, 0x00, 0x7e, 0x40, 0x40, 0x40, 0x78, 0x40, 0x40, 0x40, 0x40, 0x00, 0x00,
+    ], // 'F'
+    [
+        0x00, 0x00, 0x3c, 0x42, 0x40, 0x40, 0x40, 0x4e, 0x42, 0x46, 0x3a, 0x00, 0x00,
+    ], // 'G'
+    [
+        0x00, 0x00, 0x42, 0x42, 0x42, 0x42, 0x7e, 0x42, 0x42, 0x42, 0x42, 0x00, 0x00,
+    ], // 'H'
+    [
+        0x00, 0x00, 0x7c, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x7c, 0x00, 0x00,
+    ], // 'I'
+    [
+        0x00, 0x00, 0x1f, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x44, 0x38, 0x00, 0x00,
+    ], // 'J'
+    [
+        0x00, 0x00, 0x42, 0x44, 0x48, 0x50, 0x60, 0x50, 0x48, 0x44, 0x42, 0x00, 0x00,
+    ], // 'K'
+    [
+        0x00, 0x00, 0x40, 0x40, 0x40, 0x40, 0x40, 0x40, 0x40, 0x40, 0x7e, 0x00, 0x00,
+    ], // 'L'
+    [
+        0x00, 0x00, 0x82, 0x82, 0xc6, 0xaa, 0x92, 0x92, 0x82, 0x82, 0x82, 0x00, 0x00,
+    ], // 'M'
+    [
+        0x00, 0x00, 0x42, 0x42, 0x62, 0x52, 0x4a, 0x46, 0x42, 0x42, 0x42, 0x00, 0x00,
+    ], // 'N'
+    [
+        0x00, 0x00, 0x3c, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x3c, 0x00, 0x00,
+    ], // 'O'
+    [
+        0x00, 0x00, 0x7c, 0x42, 0x42, 0x42, 0x7c, 0x40, 0x40, 0x40, 0x40, 0x00, 0x00,
+    ], // 'P'
+    [
+        0x00, 0x00, 0x3c, 0x42, 0x42, 0x42, 0x42, 0x42, 0x52, 0x4a, 0x3c, 0x02, 0x00,
+    ], // 'Q'
+    [
+        0x00, 0x00, 0x7c, 0x42, 0x42, 0x42, 0x7c, 0x50, 0x48, 0x44, 0x42, 0x00, 0x00,
+    ], // 'R'
+    [
+        0x00, 0x00, 0x3c, 0x42, 0x40, 0x40, 0x3c, 0x02, 0x02, 0x42, 0x3c, 0x00, 0x00,
+    ], // 'S'
+    [
+        0x00, 0x00, 0xfe, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x00, 0x00,
+    ], // 'T'
+    [
+        0x00, 0x00, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x3c, 0x00, 0x00,
+    ], // 'U'
+    [
+        0x00, 0x00, 0x82, 0x82, 0x44, 0x44, 0x44, 0x28, 0x28, 0x28, 0x10, 0x00, 0x00,
+    ], // 'V'
+    [
+        0x00, 0x00, 0x82, 0x82, 0x82, 0x82, 0x92, 0x92, 0x92, 0xaa, 0x44, 0x00, 0x00,
+    ], // 'W'
+    [
+        0x00, 0x00, 0x82, 0x82, 0x44, 0x28, 0x10, 0x28, 0x44, 0x82, 0x82, 0x00, 0x00,
+    ], // 'X'
+    [
+        0x00, 0x00, 0x82, 0x82, 0x44, 0x28, 0x10, 0x10, 0x10, 0x10, 0x10, 0x00, 0x00,
+    ], // 'Y'
+    [
+        0x00, 0x00, 0x7e, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x40, 0x7e, 0x00, 0x00,
+    ], // 'Z'
+    [
+        0x00, 0x00, 0x3c, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x3c, 0x00, 0x00,
+    ], // '['
+    [
+        0x00, 0x00, 0x80, 0x80, 0x40, 0x20, 0x10, 0x08, 0x04, 0x02, 0x02, 0x00, 0x00,
+    ], // '\\'
+    [
+        0x00, 0x00, 0x78, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x78, 0x00, 0x00,
+    ], // ']'
+    [
+        0x00, 0x00, 0x10, 0x28, 0x44, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ], // '^'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xfe, 0x00,
+    ], // '_'
+    [
+        0x00, 0x10, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ], // '`'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x3c, 0x02, 0x3e, 0x42, 0x46, 0x3a, 0x00, 0x00,
+    ], // 'a'
+    [
+        0x00, 0x00, 0x40, 0x40, 0x40, 0x5c, 0x62, 0x42, 0x42, 0x62, 0x5c, 0x00, 0x00,
+    ], // 'b'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x3c, 0x42, 0x40, 0x40, 0x42, 0x3c, 0x00, 0x00,
+    ], // 'c'
+    [
+        0x00, 0x00, 0x02, 0x02, 0x02, 0x3a, 0x46, 0x42, 0x42, 0x46, 0x3a, 0x00, 0x00,
+    ], // 'd'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x3c, 0x42, 0x7e, 0x40, 0x42, 0x3c, 0x00, 0x00,
+    ], // 'e'
+    [
+        0x00, 0x00, 0x1c, 0x22, 0x20, 0x20, 0x7c, 0x20, 0x20, 0x20, 0x20, 0x00, 0x00,
+    ], // 'f'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x3a, 0x44, 0x44, 0x38, 0x40, 0x3c, 0x42, 0x3c,
+    ], // 'g'
+    [
+        0x00, 0x00, 0x40, 0x40, 0x40, 0x5c, 0x62, 0x42, 0x42, 0x42, 0x42, 0x00, 0x00,
+    ], // 'h'
+    [
+        0x00, 0x00, 0x00, 0x10, 0x00, 0x30, 0x10, 0x10, 0x10, 0x10, 0x7c, 0x00, 0x00,
+    ], // 'i'
+    [
+        0x00, 0x00, 0x00, 0x04, 0x00, 0x0c, 0x04, 0x04, 0x04, 0x04, 0x44, 0x44, 0x38,
+    ], // 'j'
+    [
+        0x00, 0x00, 0x40, 0x40, 0x40, 0x44, 0x48, 0x70, 0x48, 0x44, 0x42, 0x00, 0x00,
+    ], // 'k'
+    [
+        0x00, 0x00, 0x30, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x7c, 0x00, 0x00,
+    ], // 'l'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0xec, 0x92, 0x92, 0x92, 0x92, 0x82, 0x00, 0x00,
+    ], // 'm'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x5c, 0x62, 0x42, 0x42, 0x42, 0x42, 0x00, 0x00,
+    ], // 'n'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x3c, 0x42, 0x42, 0x42, 0x42, 0x3c, 0x00, 0x00,
+    ], // 'o'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x5c, 0x62, 0x42, 0x62, 0x5c, 0x40, 0x40, 0x40,
+    ], // 'p'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x3a, 0x46, 0x42, 0x46, 0x3a, 0x02, 0x02, 0x02,
+    ], // 'q'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x5c, 0x22, 0x20, 0x20, 0x20, 0x20, 0x00, 0x00,
+    ], // 'r'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x3c, 0x42, 0x30, 0x0c, 0x42, 0x3c, 0x00, 0x00,
+    ], // 's'
+    [
+        0x00, 0x00, 0x00, 0x20, 0x20, 0x7c, 0x20, 0x20, 0x20, 0x22, 0x1c, 0x00, 0x00,
+    ], // 't'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x44, 0x44, 0x44, 0x44, 0x44, 0x3a, 0x00, 0x00,
+    ], // 'u'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x44, 0x44, 0x44, 0x28, 0x28, 0x10, 0x00, 0x00,
+    ], // 'v'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x82, 0x82, 0x92, 0x92, 0xaa, 0x44, 0x00, 0x00,
+    ], // 'w'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x42, 0x24, 0x18, 0x18, 0x24, 0x42, 0x00, 0x00,
+    ], // 'x'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x42, 0x42, 0x42, 0x46, 0x3a, 0x02, 0x42, 0x3c,
+    ], // 'y'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x7e, 0x04, 0x08, 0x10, 0x20, 0x7e, 0x00, 0x00,
+    ], // 'z'
+    [
+        0x00, 0x00, 0x0e, 0x10, 0x10, 0x08, 0x30, 0x08, 0x10, 0x10, 0x0e, 0x00, 0x00,
+    ], // '{'
+    [
+        0x00, 0x00, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x00, 0x00,
+    ], // '|'
+    [
+        0x00, 0x00, 0x70, 0x08, 0x08, 0x10, 0x0c, 0x10, 0x08, 0x08, 0x70, 0x00, 0x00,
+    ], // '}'
+    [
+        0x00, 0x00, 0x24, 0x54, 0x48, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ], // '~'
+];
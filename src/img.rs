@@ -1,12 +1,49 @@
+use std::sync::OnceLock;
+
 #[cfg(feature = "img")]
 pub use image::NormalImage;
 
-use crate::BWDataErr;
+use crate::{
+    threshold::{Binarize, Binarized},
+    BWDataErr, Threshold,
+};
 
 pub trait ImageData {
     fn to_bw_data(&self) -> Result<Vec<u8>, BWDataErr>;
     fn image_config(&self) -> BWImageSize;
 
+    /// Get the luminance of every pixel, row by row without padding.
+    /// The default implementation expands the black and white data into 0 and 255
+    fn to_gray_data(&self) -> Result<Vec<u8>, BWDataErr> {
+        let size = self.image_config();
+        let bw = self.to_bw_data()?;
+        if (bw.len() as u64) < size.get_padded_bytes_len() {
+            return Err(BWDataErr::WrongSize(size.width, size.height, bw.len()));
+        }
+        let row_len = size.width.div_ceil(8) as usize;
+        Ok((0..size.height as usize)
+            .flat_map(|y| {
+                let row = &bw[y * row_len..(y + 1) * row_len];
+                (0..size.width as usize).map(move |x| {
+                    if row[x / 8] & (1 << (7 - x % 8)) != 0 {
+                        255
+                    } else {
+                        0
+                    }
+                })
+            })
+            .collect())
+    }
+
+    /// Binarize the luminance of this source with another strategy, e.g. `threshold::Adaptive`
+    #[inline(always)]
+    fn binarize_with<B: Binarize>(&self, binarizer: B) -> Binarized<'_, Self, B>
+    where
+        Self: Sized,
+    {
+        Binarized::new(self, binarizer)
+    }
+
     #[inline(always)]
     fn parse_bw_image(&self) -> Result<BWImage, BWDataErr>
     where
@@ -16,54 +53,72 @@ pub trait ImageData {
     }
 }
 
+/// Raw RGB24 buffer, a `RawData` with `PixelFormat::Rgb24`
 #[derive(Clone)]
 pub struct RgbData<'a> {
-    data: &'a [u8],
-    height: u32,
-    width: u32,
-    bw_threshold: u8,
+    raw: RawData<'a>,
 }
 
 #[cfg(feature = "img")]
 mod image {
     use image::GenericImageView;
 
-    use crate::BWDataErr;
+    use crate::{AlphaMode, BWDataErr, LumaModel, Threshold};
 
-    use super::{is_white, ImageData};
+    use super::ImageData;
 
     pub struct NormalImage<'a> {
         img: &'a image::DynamicImage,
-        threshold: u8,
+        threshold: Threshold,
+        luma_model: LumaModel,
+        alpha_mode: AlphaMode,
     }
     impl<'a> NormalImage<'a> {
         pub fn new(img: &'a image::DynamicImage) -> Self {
             Self {
                 img,
-                threshold: 128,
+                threshold: Threshold::default(),
+                luma_model: LumaModel::default(),
+                alpha_mode: AlphaMode::default(),
             }
         }
 
         pub fn set_bw_threshold(&mut self, threshold: u8) {
+            self.threshold = Threshold::Fixed(threshold)
+        }
+
+        pub fn set_threshold(&mut self, threshold: Threshold) {
             self.threshold = threshold
         }
+
+        pub fn set_luma_model(&mut self, model: LumaModel) {
+            self.luma_model = model
+        }
+
+        pub fn set_alpha_mode(&mut self, mode: AlphaMode) {
+            self.alpha_mode = mode
+        }
+
+        /// Get the threshold used for binarization, computing it from the image if needed.
+        /// The result can be reused as `Threshold::Fixed` for other images
+        pub fn resolve_threshold(&self) -> u8 {
+            self.threshold
+                .resolve(self.img.pixels().map(|(_, _, pix)| self.pixel_luma(pix)))
+        }
+
+        #[inline(always)]
+        fn pixel_luma(&self, pix: image::Rgba<u8>) -> u8 {
+            let [r, g, b] = self.alpha_mode.apply(pix.0);
+            self.luma_model.luma(r, g, b)
+        }
     }
 
-    impl<'a> ImageData for NormalImage<'a> {
+    impl ImageData for NormalImage<'_> {
         fn to_bw_data(&self) -> Result<Vec<u8>, BWDataErr> {
-            let width = self.img.width();
-            let mut buf = vec![];
-            let mut bytes = vec![];
-            for (x, _, pix) in self.img.pixels() {
-                buf.push(is_white(pix[0], pix[1], pix[2], self.threshold));
-
-                if x == width - 1 || buf.len() == 8 {
-                    bytes.push(super::to_bw_data_byte(&buf));
-                    buf.clear();
-                }
-            }
-
-            Ok(bytes)
+            let threshold = self.resolve_threshold();
+            Ok(super::pack_bw_data(self.image_config(), |x, y| {
+                self.pixel_luma(self.img.get_pixel(x, y)) > threshold
+            }))
         }
 
         fn image_config(&self) -> super::BWImageSize {
@@ -72,54 +127,274 @@ mod image {
                 height: self.img.height(),
             }
         }
+
+        fn to_gray_data(&self) -> Result<Vec<u8>, BWDataErr> {
+            Ok(self
+                .img
+                .pixels()
+                .map(|(_, _, pix)| self.pixel_luma(pix))
+                .collect())
+        }
     }
 }
 
 impl<'a> RgbData<'a> {
     pub fn new(data: &'a [u8], width: u32, height: u32) -> Self {
+        Self::with_stride(data, width, height, width as usize * 3)
+    }
+
+    /// Create with the length of each row in bytes, including the padding at the end of the row
+    pub fn with_stride(data: &'a [u8], width: u32, height: u32, stride: usize) -> Self {
         Self {
-            data,
-            height,
-            width,
-            bw_threshold: 128,
+            raw: RawData::with_stride(data, width, height, PixelFormat::Rgb24, stride),
         }
     }
 
     pub fn set_bw_threshold(&mut self, threshold: u8) {
-        self.bw_threshold = threshold;
+        self.raw.set_bw_threshold(threshold);
+    }
+
+    pub fn set_threshold(&mut self, threshold: Threshold) {
+        self.raw.set_threshold(threshold);
+    }
+
+    pub fn set_luma_model(&mut self, model: LumaModel) {
+        self.raw.set_luma_model(model);
+    }
+
+    /// Get the threshold used for binarization, computing it from the data if needed.
+    /// The result can be reused as `Threshold::Fixed` for other frames
+    pub fn resolve_threshold(&self) -> u8 {
+        self.raw.resolve_threshold()
+    }
+}
+
+/// Check that the buffer holds `height` rows of `row_len` bytes, `stride` bytes apart.
+/// The padding after the last row may be missing
+fn validate_buffer(
+    data: &[u8],
+    stride: usize,
+    row_len: usize,
+    size: BWImageSize,
+) -> Result<(), BWDataErr> {
+    if size.height > 0 && stride < row_len {
+        return Err(BWDataErr::WrongStride(stride, row_len));
+    }
+    let needed = match size.height {
+        0 => Some(0),
+        height => stride
+            .checked_mul(height as usize - 1)
+            .and_then(|len| len.checked_add(row_len)),
+    };
+    match needed {
+        Some(needed) if data.len() >= needed => Ok(()),
+        _ => Err(BWDataErr::WrongSize(size.width, size.height, data.len())),
+    }
+}
+
+/// Iterate the rows of a buffer, skipping the padding at the end of each row
+fn row_iter(
+    data: &[u8],
+    stride: usize,
+    row_len: usize,
+    height: u32,
+) -> impl Iterator<Item = &[u8]> {
+    data.chunks(stride.max(1))
+        .take(height as usize)
+        .map(move |row| &row[..row_len.min(row.len())])
+}
+
+/// How the alpha channel is handled before binarization
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AlphaMode {
+    /// Use the color channels as they are
+    #[default]
+    Ignore,
+    /// Blend the pixel over the background color
+    Composite([u8; 3]),
+    /// Pixels with alpha below the cutoff become the color, the others are used as they are
+    Cutoff { cutoff: u8, color: [u8; 3] },
+}
+
+impl AlphaMode {
+    #[inline(always)]
+    pub fn apply(&self, [r, g, b, a]: [u8; 4]) -> [u8; 3] {
+        match self {
+            Self::Ignore => [r, g, b],
+            Self::Composite(background) => {
+                let blend = |fg: u8, bg: u8| {
+                    ((fg as u16 * a as u16 + bg as u16 * (255 - a as u16) + 127) / 255) as u8
+                };
+                [
+                    blend(r, background[0]),
+                    blend(g, background[1]),
+                    blend(b, background[2]),
+                ]
+            }
+            Self::Cutoff { cutoff, color } => {
+                if a < *cutoff {
+                    *color
+                } else {
+                    [r, g, b]
+                }
+            }
+        }
+    }
+}
+
+/// Layout of a pixel in raw image buffers
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PixelFormat {
+    Rgb24,
+    Rgba32,
+    Bgr24,
+    Bgra32,
+    Gray8,
+    /// 16-bit gray, little endian
+    Gray16Le,
+    /// 16-bit gray, big endian
+    Gray16Be,
+    /// 5 bits red, 6 bits green and 5 bits blue packed in a little endian u16
+    Rgb565,
+}
+
+impl PixelFormat {
+    pub fn bytes_per_pixel(&self) -> usize {
+        match self {
+            Self::Gray8 => 1,
+            Self::Gray16Le | Self::Gray16Be | Self::Rgb565 => 2,
+            Self::Rgb24 | Self::Bgr24 => 3,
+            Self::Rgba32 | Self::Bgra32 => 4,
+        }
+    }
+
+    /// Read the gray value of a pixel, `None` if the format isn't grayscale
+    #[inline(always)]
+    pub fn gray(&self, pix: &[u8]) -> Option<u8> {
+        match self {
+            Self::Gray8 => Some(pix[0]),
+            Self::Gray16Le => Some(pix[1]),
+            Self::Gray16Be => Some(pix[0]),
+            _ => None,
+        }
+    }
+
+    /// Read a pixel as rgba, opaque if the format has no alpha channel
+    #[inline(always)]
+    pub fn rgba(&self, pix: &[u8]) -> [u8; 4] {
+        match self {
+            Self::Rgb24 => [pix[0], pix[1], pix[2], 255],
+            Self::Rgba32 => [pix[0], pix[1], pix[2], pix[3]],
+            Self::Bgr24 => [pix[2], pix[1], pix[0], 255],
+            Self::Bgra32 => [pix[2], pix[1], pix[0], pix[3]],
+            Self::Gray8 | Self::Gray16Le | Self::Gray16Be => {
+                let v = self.gray(pix).unwrap();
+                [v, v, v, 255]
+            }
+            Self::Rgb565 => {
+                let v = u16::from_le_bytes([pix[0], pix[1]]);
+                let (r, g, b) = ((v >> 11) as u8, (v >> 5) as u8 & 0x3f, v as u8 & 0x1f);
+                [
+                    (r << 3) | (r >> 2),
+                    (g << 2) | (g >> 4),
+                    (b << 3) | (b >> 2),
+                    255,
+                ]
+            }
+        }
     }
 }
 
-#[inline(always)]
-fn is_white(r: u8, g: u8, b: u8, threshold: u8) -> bool {
-    let gray_value = (0.299 * r as f32 + 0.587 * g as f32 + 0.114 * b as f32) as u8;
-    gray_value > threshold
+/// Raw image buffer with the pixels in `PixelFormat`, row by row.
+/// Rows are `stride` bytes apart, which may include padding at the end of each row
+#[derive(Clone)]
+pub struct RawData<'a> {
+    data: &'a [u8],
+    height: u32,
+    width: u32,
+    format: PixelFormat,
+    stride: usize,
+    threshold: Threshold,
+    luma_model: LumaModel,
+    alpha_mode: AlphaMode,
 }
 
-fn to_bw_data_byte(data: &[bool]) -> u8 {
-    // 8 bits per byte, one bit presents one pixel, high bit is the first pixel
-    let mut bw_bit = 0u8;
-    for (i, bit) in data.iter().enumerate() {
-        if *bit {
-            bw_bit |= 1 << (7 - i);
+impl<'a> RawData<'a> {
+    pub fn new(data: &'a [u8], width: u32, height: u32, format: PixelFormat) -> Self {
+        Self::with_stride(
+            data,
+            width,
+            height,
+            format,
+            width as usize * format.bytes_per_pixel(),
+        )
+    }
+
+    /// Create with the length of each row in bytes, including the padding at the end of the row
+    pub fn with_stride(
+        data: &'a [u8],
+        width: u32,
+        height: u32,
+        format: PixelFormat,
+        stride: usize,
+    ) -> Self {
+        Self {
+            data,
+            height,
+            width,
+            format,
+            stride,
+            threshold: Threshold::default(),
+            luma_model: LumaModel::default(),
+            alpha_mode: AlphaMode::default(),
         }
     }
-    bw_bit
+
+    pub fn set_bw_threshold(&mut self, threshold: u8) {
+        self.threshold = Threshold::Fixed(threshold);
+    }
+
+    pub fn set_threshold(&mut self, threshold: Threshold) {
+        self.threshold = threshold;
+    }
+
+    pub fn set_luma_model(&mut self, model: LumaModel) {
+        self.luma_model = model;
+    }
+
+    pub fn set_alpha_mode(&mut self, mode: AlphaMode) {
+        self.alpha_mode = mode;
+    }
+
+    /// Get the threshold used for binarization, computing it from the data if needed.
+    /// The result can be reused as `Threshold::Fixed` for other frames
+    pub fn resolve_threshold(&self) -> u8 {
+        self.threshold.resolve(self.luma_iter())
+    }
+
+    fn luma_iter(&self) -> impl Iterator<Item = u8> + '_ {
+        let bytes_per_pixel = self.format.bytes_per_pixel();
+        row_iter(
+            self.data,
+            self.stride,
+            self.width as usize * bytes_per_pixel,
+            self.height,
+        )
+        .flat_map(move |row| row.chunks_exact(bytes_per_pixel))
+        .map(|pix| {
+            self.format.gray(pix).unwrap_or_else(|| {
+                let [r, g, b] = self.alpha_mode.apply(self.format.rgba(pix));
+                self.luma_model.luma(r, g, b)
+            })
+        })
+    }
 }
 
-impl<'a> ImageData for RgbData<'a> {
+impl ImageData for RawData<'_> {
     fn to_bw_data(&self) -> Result<Vec<u8>, BWDataErr> {
         Ok(self
-            .data
-            .chunks(3 * 8)
-            .map(|c| {
-                to_bw_data_byte(
-                    &c.chunks(3)
-                        .map(|pix| is_white(pix[0], pix[1], pix[2], self.bw_threshold))
-                        .collect::<Vec<_>>(),
-                )
-            })
-            .collect())
+            .threshold
+            .binarize(&self.to_gray_data()?, self.image_config()))
     }
 
     fn image_config(&self) -> BWImageSize {
@@ -128,6 +403,147 @@ impl<'a> ImageData for RgbData<'a> {
             height: self.height,
         }
     }
+
+    fn to_gray_data(&self) -> Result<Vec<u8>, BWDataErr> {
+        validate_buffer(
+            self.data,
+            self.stride,
+            self.width as usize * self.format.bytes_per_pixel(),
+            self.image_config(),
+        )?;
+        Ok(self.luma_iter().collect())
+    }
+}
+
+/// Model converting rgb values into the gray value compared with the threshold
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LumaModel {
+    /// ITU-R BT.601 weights(0.299, 0.587, 0.114) on the sRGB values
+    #[default]
+    Bt601,
+    /// ITU-R BT.709 weights(0.2126, 0.7152, 0.0722) on the sRGB values
+    Bt709,
+    /// Mean of the three channels
+    Average,
+    Red,
+    Green,
+    Blue,
+    /// Brightest channel, colored ink on white paper turns white
+    Max,
+    /// Darkest channel, any colored ink turns black
+    Min,
+    /// BT.709 weights applied in linear light, the result is encoded back into sRGB
+    Linear,
+}
+
+impl LumaModel {
+    #[inline(always)]
+    pub fn luma(&self, r: u8, g: u8, b: u8) -> u8 {
+        let weighted =
+            |wr: f32, wg: f32, wb: f32| (wr * r as f32 + wg * g as f32 + wb * b as f32) as u8;
+        match self {
+            Self::Bt601 => weighted(0.299, 0.587, 0.114),
+            Self::Bt709 => weighted(0.2126, 0.7152, 0.0722),
+            Self::Average => ((r as u16 + g as u16 + b as u16) / 3) as u8,
+            Self::Red => r,
+            Self::Green => g,
+            Self::Blue => b,
+            Self::Max => r.max(g).max(b),
+            Self::Min => r.min(g).min(b),
+            Self::Linear => {
+                let table = srgb_to_linear_table();
+                let y = 0.2126 * table[r as usize]
+                    + 0.7152 * table[g as usize]
+                    + 0.0722 * table[b as usize];
+                let encoded = if y <= 0.003_130_8 {
+                    12.92 * y
+                } else {
+                    1.055 * y.powf(1.0 / 2.4) - 0.055
+                };
+                (encoded * 255.0).round().clamp(0.0, 255.0) as u8
+            }
+        }
+    }
+}
+
+fn srgb_to_linear_table() -> &'static [f32; 256] {
+    static TABLE: OnceLock<[f32; 256]> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        std::array::from_fn(|v| {
+            let v = v as f32 / 255.0;
+            if v <= 0.04045 {
+                v / 12.92
+            } else {
+                ((v + 0.055) / 1.055).powf(2.4)
+            }
+        })
+    })
+}
+
+/// Set the pixels from `from` to `to`(exclusive) of a row to white or black
+pub(crate) fn fill_bits(row: &mut [u8], from: usize, to: usize, white: bool) {
+    update_bits(row, from, to, |byte, mask| {
+        if white {
+            *byte |= mask;
+        } else {
+            *byte &= !mask;
+        }
+    });
+}
+
+/// Invert the pixels from `from` to `to`(exclusive) of a row
+pub(crate) fn invert_bits(row: &mut [u8], from: usize, to: usize) {
+    update_bits(row, from, to, |byte, mask| *byte ^= mask);
+}
+
+/// Call `update` with every byte of the row covering bits from `from` to `to`,
+/// and the mask of the covered bits
+fn update_bits(row: &mut [u8], from: usize, to: usize, update: impl Fn(&mut u8, u8)) {
+    for (i, byte) in row
+        .iter_mut()
+        .enumerate()
+        .take(to.div_ceil(8))
+        .skip(from / 8)
+    {
+        let start = from.max(i * 8) - i * 8;
+        let end = to.min(i * 8 + 8) - i * 8;
+        update(byte, (0xffu8 >> start) & !(0xffu16 >> end) as u8);
+    }
+}
+
+/// Pack the pixels into bw data, every row is padded to whole bytes
+pub(crate) fn pack_bw_data(
+    size: BWImageSize,
+    mut is_white: impl FnMut(u32, u32) -> bool,
+) -> Vec<u8> {
+    let row_len = size.width.div_ceil(8) as usize;
+    let mut bytes = vec![0u8; row_len * size.height as usize];
+    for y in 0..size.height {
+        let row = &mut bytes[y as usize * row_len..(y as usize + 1) * row_len];
+        for x in 0..size.width {
+            if is_white(x, y) {
+                row[x as usize / 8] |= 1 << (7 - x % 8);
+            }
+        }
+    }
+    bytes
+}
+
+impl ImageData for RgbData<'_> {
+    #[inline(always)]
+    fn to_bw_data(&self) -> Result<Vec<u8>, BWDataErr> {
+        self.raw.to_bw_data()
+    }
+
+    #[inline(always)]
+    fn image_config(&self) -> BWImageSize {
+        self.raw.image_config()
+    }
+
+    #[inline(always)]
+    fn to_gray_data(&self) -> Result<Vec<u8>, BWDataErr> {
+        self.raw.to_gray_data()
+    }
 }
 
 pub trait BWByteData {
@@ -150,27 +566,66 @@ pub struct BWImageSize {
 impl BWImageSize {
     #[inline(always)]
     pub fn get_padded_bytes_len(&self) -> u64 {
-        ((self.width as u64 + 7) / 8) * self.height as u64
+        (self.width as u64).div_ceil(8) * self.height as u64
+    }
+
+    /// Get the bytes of a row, every row is padded to whole bytes
+    #[inline(always)]
+    pub fn get_padded_row_len(&self) -> usize {
+        self.width.div_ceil(8) as usize
     }
 }
 
 /// Black and white image
 /// The image is stored as a 1-bit per pixel bitmap
 /// The high bit is the first pixel
-#[derive(Clone, Debug)]
+/// Every row starts at a new byte, the padding bits at the end of the rows are zero
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct BWImage {
     pub size: BWImageSize,
     pub pixels: Vec<u8>,
 }
 
+/// State given to `IterDirection::next`.
+/// `pixels` can belong to a larger image, so directions must read the pixels
+/// through `get` or `row_byte` instead of indexing `pixels` directly
 #[derive(Clone)]
 pub struct BWIterState<'a> {
+    /// Size of the iterated area
     pub size: BWImageSize,
     pub current: (u32, u32),
     pub pixels: &'a [u8],
+    /// Position of the iterated area in the pixels
+    pub origin: (u32, u32),
+    /// Bytes of a row in the pixels
+    pub row_len: usize,
+}
+
+impl BWIterState<'_> {
+    /// Get the pixel at `(x, y)` of the iterated area, `true` is white
+    #[inline(always)]
+    pub fn get(&self, x: u32, y: u32) -> bool {
+        let (x, y) = (x + self.origin.0, y + self.origin.1);
+        self.pixels[y as usize * self.row_len + x as usize / 8] & (0x80 >> (x % 8)) != 0
+    }
+
+    /// Get up to 8 pixels of the row from `(x, y)`, high bit is the first pixel.
+    /// The bits after the returned length are cleared
+    #[inline(always)]
+    pub fn row_byte(&self, x: u32, y: u32) -> (u8, usize) {
+        let len = 8.min(self.size.width - x) as usize;
+        let (x, y) = (x + self.origin.0, y + self.origin.1);
+        let idx = y as usize * self.row_len + x as usize / 8;
+        let shift = x % 8;
+        let mut byte = self.pixels[idx] << shift;
+        if shift != 0 && shift as usize + len > 8 {
+            byte |= self.pixels[idx + 1] >> (8 - shift);
+        }
+        (byte & (0xff00u16 >> len) as u8, len)
+    }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum IterOutput {
     Byte { byte: u8, len: usize },
     NewLine,
@@ -181,126 +636,389 @@ pub trait IterDirection {
     fn next(&mut self, state: BWIterState) -> Option<((u32, u32), IterOutput)>;
 }
 
+/// Direction returning a new line after every line of bytes,
+/// which can be iterated from the back and started at any pixel
+pub trait SeekDirection: IterDirection {
+    /// Get the number of lines and the bytes in every line of an area
+    fn layout(&self, size: BWImageSize) -> (u32, u32);
+
+    /// Get the position before returning the byte `index` of `line`.
+    /// The byte after the last one is the new line
+    fn position(&self, size: BWImageSize, line: u32, index: u32) -> (u32, u32);
+
+    /// Get the line and the index of the byte containing the pixel `(x, y)`
+    fn locate(&self, size: BWImageSize, x: u32, y: u32) -> (u32, u32);
+}
+
+/// Direction whose bytes can be mapped back to the pixels
+pub trait PixelDirection: IterDirection {
+    /// Get the position of the pixel `i` of the byte returned at `current`, and its bit in the byte
+    fn pixel(&self, size: BWImageSize, current: (u32, u32), i: u32) -> ((u32, u32), u8);
+}
+
 impl<D: IterDirection> Iterator for BWByteIter<'_, D> {
     type Item = IterOutput;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.direction
-            .next(BWIterState {
-                size: self.size,
-                current: self.current,
-                pixels: self.pixels,
-            })
-            .map(|(current, out)| {
-                self.current = current;
-                out
-            })
+        let (current, out) = self.direction.next(self.state(self.current))?;
+        self.current = current;
+        self.front += 1;
+        Some(out)
     }
 }
 
+/// Define a direction struct implementing `IterDirection`, attributes like derives
+/// can be put before the name
 #[macro_export]
 macro_rules! define_direction {
-    ($type:ident, $impl_bo:tt) => {
+    ($(#[$attr:meta])* $type:ident, $impl_bo:tt) => {
+        $(#[$attr])*
         pub struct $type;
 
         impl $crate::img::IterDirection for $type $impl_bo
     };
 }
 
+/// Built-in directions
+///
+/// - `Horizontal`: left to right from the top row, the high bit is the first pixel
+/// - `HorizontalRev`: left to right from the top row, the low bit is the first pixel
+/// - `BottomUp`: left to right from the bottom row, the high bit is the first pixel
+/// - `BottomUpRev`: left to right from the bottom row, the low bit is the first pixel
+/// - `Rotate180`: right to left from the bottom row, the high bit is the first pixel,
+///   like `Horizontal` on the image rotated 180 degrees
+/// - `Vertical`: top to bottom from the left column, the high bit is the first pixel
+/// - `VerticalRev`: top to bottom from the left column, the low bit is the first pixel
+/// - `Pages`: pages of 8 rows from the top, each byte is a column of the page from left to
+///   right with the low bit on top, like SSD1306 and SH1106 displays
 pub mod iter_direction {
     use crate::{BWImageSize, IterOutput};
 
-    define_direction!(Horizontal, {
-        fn next(&mut self, state: crate::BWIterState) -> Option<((u32, u32), IterOutput)> {
-            let (x, y) = state.current;
-            let BWImageSize { width, height } = state.size;
-            if y >= height {
-                None
-            } else if x >= width {
-                Some(((0, y + 1), IterOutput::NewLine))
-            } else {
-                Some((
-                    (x + 8, y),
-                    IterOutput::Byte {
-                        byte: state.pixels[((y * width + x) / 8) as usize],
-                        len: 8.min((width - x) as usize),
-                    },
-                ))
+    macro_rules! map_pixels {
+        ($type:ident, |$size:pat, $current:pat, $i:ident| $body:expr) => {
+            impl $crate::img::PixelDirection for $type {
+                #[inline(always)]
+                fn pixel(
+                    &self,
+                    $size: BWImageSize,
+                    $current: (u32, u32),
+                    $i: u32,
+                ) -> ((u32, u32), u8) {
+                    $body
+                }
             }
-        }
+        };
+    }
+
+    map_pixels!(Horizontal, |_, (x, y), i| ((x + i, y), 0x80 >> i));
+    map_pixels!(HorizontalRev, |_, (x, y), i| ((x + i, y), 1 << i));
+    map_pixels!(BottomUp, |size, (x, line), i| {
+        ((x + i, size.height - 1 - line), 0x80 >> i)
     });
-    define_direction!(Vertical, {
-        fn next(&mut self, state: crate::BWIterState) -> Option<((u32, u32), IterOutput)> {
-            let (x, y) = state.current;
-            let BWImageSize { width, height } = state.size;
-            if x >= width {
-                None
-            } else if y >= height {
-                Some(((x + 1, 0), IterOutput::NewLine))
-            } else {
-                let mut byt = 0u8;
-                let width_in_byte = ((width as f64) / 8f64).ceil() as u32;
-                let (row_byte, rev_idx_at_byte) = (x / 8, (7 - x % 8));
-
-                let from_byte = y * width_in_byte + row_byte;
-                let mut len = 8;
-                for i in 0..8 {
-                    let pos = from_byte + i * width_in_byte;
-                    if pos >= state.pixels.len() as u32 {
-                        len = i as usize;
-                        break;
-                    }
+    map_pixels!(BottomUpRev, |size, (x, line), i| {
+        ((x + i, size.height - 1 - line), 1 << i)
+    });
+    map_pixels!(Rotate180, |size, (pos, line), i| {
+        (
+            (size.width - 1 - pos - i, size.height - 1 - line),
+            0x80 >> i,
+        )
+    });
+    map_pixels!(Vertical, |_, (x, y), i| ((x, y + i), 0x80 >> i));
+    map_pixels!(VerticalRev, |_, (x, y), i| ((x, y + i), 1 << i));
+    map_pixels!(Pages, |_, (x, page), i| ((x, page * 8 + i), 1 << i));
+
+    macro_rules! seek_lines {
+        (
+            $type:ident,
+            |$layout_size:ident| $layout:expr,
+            |$line:ident, $index:ident| $position:expr,
+            |$size:pat, $x:ident, $y:ident| $locate:expr
+        ) => {
+            impl $crate::img::SeekDirection for $type {
+                #[inline(always)]
+                fn layout(&self, $layout_size: BWImageSize) -> (u32, u32) {
+                    $layout
+                }
 
-                    byt |= ((state.pixels[pos as usize] >> rev_idx_at_byte) & 0b1) << (7 - i);
+                #[inline(always)]
+                fn position(&self, _size: BWImageSize, $line: u32, $index: u32) -> (u32, u32) {
+                    $position
                 }
 
-                Some(((x, y + len as u32), IterOutput::Byte { byte: byt, len }))
+                #[inline(always)]
+                fn locate(&self, $size: BWImageSize, $x: u32, $y: u32) -> (u32, u32) {
+                    $locate
+                }
+            }
+        };
+    }
+
+    seek_lines!(
+        Horizontal,
+        |size| (size.height, size.width.div_ceil(8)),
+        |line, i| (i * 8, line),
+        |_, x, y| (y, x / 8)
+    );
+    seek_lines!(
+        HorizontalRev,
+        |size| (size.height, size.width.div_ceil(8)),
+        |line, i| (i * 8, line),
+        |_, x, y| (y, x / 8)
+    );
+    seek_lines!(
+        BottomUp,
+        |size| (size.height, size.width.div_ceil(8)),
+        |line, i| (i * 8, line),
+        |size, x, y| (size.height - 1 - y, x / 8)
+    );
+    seek_lines!(
+        BottomUpRev,
+        |size| (size.height, size.width.div_ceil(8)),
+        |line, i| (i * 8, line),
+        |size, x, y| (size.height - 1 - y, x / 8)
+    );
+    seek_lines!(
+        Rotate180,
+        |size| (size.height, size.width.div_ceil(8)),
+        |line, i| (i * 8, line),
+        |size, x, y| (size.height - 1 - y, (size.width - 1 - x) / 8)
+    );
+    seek_lines!(
+        Vertical,
+        |size| (size.width, size.height.div_ceil(8)),
+        |line, i| (line, i * 8),
+        |_, x, y| (x, y / 8)
+    );
+    seek_lines!(
+        VerticalRev,
+        |size| (size.width, size.height.div_ceil(8)),
+        |line, i| (line, i * 8),
+        |_, x, y| (x, y / 8)
+    );
+    seek_lines!(
+        Pages,
+        |size| (size.height.div_ceil(8), size.width),
+        |line, i| (i, line),
+        |_, x, y| (y / 8, x)
+    );
+
+    define_direction!(
+        #[derive(Clone, Copy, Debug, Default)]
+        Horizontal,
+        {
+            fn next(&mut self, state: crate::BWIterState) -> Option<((u32, u32), IterOutput)> {
+                let (x, y) = state.current;
+                let BWImageSize { width, height } = state.size;
+                if y >= height {
+                    None
+                } else if x >= width {
+                    Some(((0, y + 1), IterOutput::NewLine))
+                } else {
+                    let (byte, len) = state.row_byte(x, y);
+                    Some(((x + 8, y), IterOutput::Byte { byte, len }))
+                }
             }
         }
-    });
-    define_direction!(VerticalRev, {
-        fn next(&mut self, state: crate::BWIterState) -> Option<((u32, u32), IterOutput)> {
-            let (x, y) = state.current;
-            let BWImageSize { width, height } = state.size;
-            if x >= width {
-                None
-            } else if y >= height {
-                Some(((x + 1, 0), IterOutput::NewLine))
-            } else {
-                let mut byt = 0u8;
-                let width_in_byte = ((width as f64) / 8f64).ceil() as u32;
-                let (row_byte, rev_idx_at_byte) = (x / 8, (7 - x % 8));
-
-                let from_byte = y * width_in_byte + row_byte;
-                let mut len = 8;
-                for i in 0..8 {
-                    let pos = from_byte + i * width_in_byte;
-                    if pos >= state.pixels.len() as u32 {
-                        len = i as usize;
-                        break;
+    );
+    define_direction!(
+        #[derive(Clone, Copy, Debug, Default)]
+        HorizontalRev,
+        {
+            fn next(&mut self, state: crate::BWIterState) -> Option<((u32, u32), IterOutput)> {
+                let (x, y) = state.current;
+                let BWImageSize { width, height } = state.size;
+                if y >= height {
+                    None
+                } else if x >= width {
+                    Some(((0, y + 1), IterOutput::NewLine))
+                } else {
+                    let (byte, len) = state.row_byte(x, y);
+                    Some((
+                        (x + 8, y),
+                        IterOutput::Byte {
+                            byte: byte.reverse_bits(),
+                            len,
+                        },
+                    ))
+                }
+            }
+        }
+    );
+    define_direction!(
+        #[derive(Clone, Copy, Debug, Default)]
+        BottomUp,
+        {
+            /// The current position is `(x, rows from the bottom)`
+            fn next(&mut self, state: crate::BWIterState) -> Option<((u32, u32), IterOutput)> {
+                let (x, line) = state.current;
+                let BWImageSize { width, height } = state.size;
+                if line >= height {
+                    None
+                } else if x >= width {
+                    Some(((0, line + 1), IterOutput::NewLine))
+                } else {
+                    let (byte, len) = state.row_byte(x, height - 1 - line);
+                    Some(((x + 8, line), IterOutput::Byte { byte, len }))
+                }
+            }
+        }
+    );
+    define_direction!(
+        #[derive(Clone, Copy, Debug, Default)]
+        BottomUpRev,
+        {
+            /// The current position is `(x, rows from the bottom)`
+            fn next(&mut self, state: crate::BWIterState) -> Option<((u32, u32), IterOutput)> {
+                let (x, line) = state.current;
+                let BWImageSize { width, height } = state.size;
+                if line >= height {
+                    None
+                } else if x >= width {
+                    Some(((0, line + 1), IterOutput::NewLine))
+                } else {
+                    let (byte, len) = state.row_byte(x, height - 1 - line);
+                    Some((
+                        (x + 8, line),
+                        IterOutput::Byte {
+                            byte: byte.reverse_bits(),
+                            len,
+                        },
+                    ))
+                }
+            }
+        }
+    );
+    define_direction!(
+        #[derive(Clone, Copy, Debug, Default)]
+        Rotate180,
+        {
+            /// The current position is `(columns from the right, rows from the bottom)`
+            fn next(&mut self, state: crate::BWIterState) -> Option<((u32, u32), IterOutput)> {
+                let (pos, line) = state.current;
+                let BWImageSize { width, height } = state.size;
+                if line >= height {
+                    None
+                } else if pos >= width {
+                    Some(((0, line + 1), IterOutput::NewLine))
+                } else {
+                    let len = 8.min(width - pos) as usize;
+                    let (byte, _) = state.row_byte(width - pos - len as u32, height - 1 - line);
+                    let byte = byte & (0xff00u16 >> len) as u8;
+                    Some((
+                        (pos + 8, line),
+                        IterOutput::Byte {
+                            byte: byte.reverse_bits() << (8 - len),
+                            len,
+                        },
+                    ))
+                }
+            }
+        }
+    );
+    define_direction!(
+        #[derive(Clone, Copy, Debug, Default)]
+        Vertical,
+        {
+            fn next(&mut self, state: crate::BWIterState) -> Option<((u32, u32), IterOutput)> {
+                let (x, y) = state.current;
+                let BWImageSize { width, height } = state.size;
+                if x >= width {
+                    None
+                } else if y >= height {
+                    Some(((x + 1, 0), IterOutput::NewLine))
+                } else {
+                    let len = 8.min(height - y) as usize;
+                    let mut byt = 0u8;
+                    for i in 0..len {
+                        byt |= (state.get(x, y + i as u32) as u8) << (7 - i);
                     }
 
-                    byt |= ((state.pixels[pos as usize] >> rev_idx_at_byte) & 0b1) << i;
+                    Some(((x, y + len as u32), IterOutput::Byte { byte: byt, len }))
                 }
+            }
+        }
+    );
+    define_direction!(
+        #[derive(Clone, Copy, Debug, Default)]
+        VerticalRev,
+        {
+            fn next(&mut self, state: crate::BWIterState) -> Option<((u32, u32), IterOutput)> {
+                let (x, y) = state.current;
+                let BWImageSize { width, height } = state.size;
+                if x >= width {
+                    None
+                } else if y >= height {
+                    Some(((x + 1, 0), IterOutput::NewLine))
+                } else {
+                    let len = 8.min(height - y) as usize;
+                    let mut byt = 0u8;
+                    for i in 0..len {
+                        byt |= (state.get(x, y + i as u32) as u8) << i;
+                    }
 
-                Some(((x, y + len as u32), IterOutput::Byte { byte: byt, len }))
+                    Some(((x, y + len as u32), IterOutput::Byte { byte: byt, len }))
+                }
             }
         }
-    });
+    );
+    define_direction!(
+        #[derive(Clone, Copy, Debug, Default)]
+        Pages,
+        {
+            /// The current position is `(x, page)`, `len` is the rows in the page
+            fn next(&mut self, state: crate::BWIterState) -> Option<((u32, u32), IterOutput)> {
+                let (x, page) = state.current;
+                let BWImageSize { width, height } = state.size;
+                let y = page * 8;
+                if y >= height {
+                    None
+                } else if x >= width {
+                    Some(((0, page + 1), IterOutput::NewLine))
+                } else {
+                    let len = 8.min(height - y) as usize;
+                    let mut byt = 0u8;
+                    for i in 0..len {
+                        byt |= (state.get(x, y + i as u32) as u8) << i;
+                    }
+
+                    Some(((x + 1, page), IterOutput::Byte { byte: byt, len }))
+                }
+            }
+        }
+    );
 }
 
 /// Iterator for black and white image
 /// The iterator will iterate through the image in a specified direction
+/// The pixels are read from rows padded to whole bytes, like `BWImage`, in every direction
 /// The iterator will return the position of the pixel and the value of the pixel(8 pixels per byte)
+/// The length is unknown, `seekable` gives an `ExactSizeIterator` and `DoubleEndedIterator`
+/// which can start at any pixel for the directions implementing `SeekDirection`
 pub struct BWByteIter<'a, D: IterDirection> {
     size: BWImageSize,
     current: (u32, u32),
     pixels: &'a [u8],
+    origin: (u32, u32),
+    row_len: usize,
     direction: D,
+    /// Number of the outputs returned
+    front: usize,
 }
 
 impl<'a, T: IterDirection> BWByteIter<'a, T> {
     pub fn new(size: &BWImageSize, pixels: &'a [u8], direction: T) -> Self {
+        Self::with_area(size, pixels, (0, 0), size.get_padded_row_len(), direction)
+    }
+
+    /// Iterate an area of `size` at `origin` of the pixels, which have `row_len` bytes per row
+    pub fn with_area(
+        size: &BWImageSize,
+        pixels: &'a [u8],
+        origin: (u32, u32),
+        row_len: usize,
+        direction: T,
+    ) -> Self {
         Self {
             direction,
             size: BWImageSize {
@@ -309,11 +1027,204 @@ impl<'a, T: IterDirection> BWByteIter<'a, T> {
             },
             current: (0, 0),
             pixels,
+            origin,
+            row_len,
+            front: 0,
+        }
+    }
+
+    fn state(&self, current: (u32, u32)) -> BWIterState<'a> {
+        BWIterState {
+            size: self.size,
+            current,
+            pixels: self.pixels,
+            origin: self.origin,
+            row_len: self.row_len,
+        }
+    }
+}
+
+impl<'a, T: SeekDirection> BWByteIter<'a, T> {
+    /// Get an iterator with the exact length, which can be iterated from the back
+    /// and started at any pixel. It continues from the outputs already returned
+    pub fn seekable(self) -> BWSeekIter<'a, T> {
+        let (lines, bytes) = self.direction.layout(self.size);
+        BWSeekIter {
+            back: lines as usize * (bytes as usize + 1),
+            bytes: self,
+        }
+    }
+
+    /// Start the iteration from the byte containing the pixel `(x, y)`
+    pub fn starting_at(self, x: u32, y: u32) -> BWSeekIter<'a, T> {
+        let mut iter = self.seekable();
+        iter.seek(x, y);
+        iter
+    }
+}
+
+/// Iterator of the bytes of a `SeekDirection`
+pub struct BWSeekIter<'a, D: SeekDirection> {
+    bytes: BWByteIter<'a, D>,
+    /// Index after the last output
+    back: usize,
+}
+
+impl<D: SeekDirection> BWSeekIter<'_, D> {
+    /// Continue the iteration from the byte containing the pixel `(x, y)` of the area,
+    /// the bytes already returned from the back are not returned again.
+    /// The iteration ends if the pixel is out of the area
+    pub fn seek(&mut self, x: u32, y: u32) {
+        let iter = &mut self.bytes;
+        if x >= iter.size.width || y >= iter.size.height {
+            iter.front = self.back;
+            return;
         }
+        let (_, bytes) = iter.direction.layout(iter.size);
+        let (line, index) = iter.direction.locate(iter.size, x, y);
+        iter.front = line as usize * (bytes as usize + 1) + index as usize;
+        iter.current = iter.direction.position(iter.size, line, index);
+    }
+}
+
+impl<D: SeekDirection> Iterator for BWSeekIter<'_, D> {
+    type Item = IterOutput;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bytes.front >= self.back {
+            return None;
+        }
+        self.bytes.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back.saturating_sub(self.bytes.front);
+        (len, Some(len))
+    }
+}
+
+impl<D: SeekDirection> ExactSizeIterator for BWSeekIter<'_, D> {}
+
+impl<D: SeekDirection> DoubleEndedIterator for BWSeekIter<'_, D> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.bytes.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        let iter = &mut self.bytes;
+        let (_, bytes) = iter.direction.layout(iter.size);
+        let line_len = bytes as usize + 1;
+        let (line, index) = ((self.back / line_len) as u32, (self.back % line_len) as u32);
+        if index == bytes {
+            return Some(IterOutput::NewLine);
+        }
+        let current = iter.direction.position(iter.size, line, index);
+        iter.direction.next(iter.state(current)).map(|(_, out)| out)
+    }
+}
+
+impl<'a, D: PixelDirection> BWByteIter<'a, D> {
+    /// Iterate the pixels instead of the bytes
+    pub fn pixels(self) -> BWPixelIter<'a, D> {
+        BWPixelIter {
+            bytes: self,
+            byte: None,
+            index: 0,
+        }
+    }
+}
+
+/// Iterator of the pixels in the order of a direction, returns `(x, y, white)`.
+/// The position is in the iterated area
+pub struct BWPixelIter<'a, D: PixelDirection> {
+    bytes: BWByteIter<'a, D>,
+    /// Position where the byte was read, the byte and its length
+    byte: Option<((u32, u32), u8, usize)>,
+    /// Next pixel in the byte
+    index: usize,
+}
+
+impl<'a, D: PixelDirection> BWPixelIter<'a, D> {
+    /// Merge the pixels into runs of one color in a row
+    pub fn runs(self) -> BWRunIter<'a, D> {
+        BWRunIter {
+            pixels: self,
+            run: None,
+        }
+    }
+}
+
+impl<D: PixelDirection> Iterator for BWPixelIter<'_, D> {
+    type Item = (u32, u32, bool);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((current, byte, len)) = self.byte {
+                if self.index < len {
+                    let ((x, y), bit) =
+                        self.bytes
+                            .direction
+                            .pixel(self.bytes.size, current, self.index as u32);
+                    self.index += 1;
+                    return Some((x, y, byte & bit != 0));
+                }
+                self.byte = None;
+            }
+
+            let current = self.bytes.current;
+            if let IterOutput::Byte { byte, len } = self.bytes.next()? {
+                self.byte = Some((current, byte, len));
+                self.index = 0;
+            }
+        }
+    }
+}
+
+/// Iterator of the runs of pixels of one color in a row, returns `(y, x_start, len, white)`.
+/// `x_start` is the leftmost pixel of the run, the runs follow the order of the direction,
+/// so the directions not walking along rows only return runs of single pixels
+pub struct BWRunIter<'a, D: PixelDirection> {
+    pixels: BWPixelIter<'a, D>,
+    /// Row, first and last pixel, and color of the current run
+    run: Option<(u32, u32, u32, bool)>,
+}
+
+impl<D: PixelDirection> Iterator for BWRunIter<'_, D> {
+    type Item = (u32, u32, u32, bool);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (x, y, white) in self.pixels.by_ref() {
+            match self.run.as_mut() {
+                Some((run_y, start, end, run_white))
+                    if *run_y == y && *run_white == white && (x == *end + 1 || x + 1 == *start) =>
+                {
+                    *start = (*start).min(x);
+                    *end = (*end).max(x);
+                }
+                _ => {
+                    if let Some((y, start, end, white)) = self.run.replace((y, x, x, white)) {
+                        return Some((y, start, end - start + 1, white));
+                    }
+                }
+            }
+        }
+        self.run
+            .take()
+            .map(|(y, start, end, white)| (y, start, end - start + 1, white))
     }
 }
 
 impl BWImage {
+    /// Create an image filled with white or black pixels
+    pub fn new(size: BWImageSize, white: bool) -> Self {
+        let mut img = Self {
+            size,
+            pixels: vec![0; size.get_padded_bytes_len() as usize],
+        };
+        img.fill(white);
+        img
+    }
+
     pub fn parse<T: ImageData>(data: &T) -> Result<Self, BWDataErr> {
         Ok(Self {
             size: data.image_config(),
@@ -331,7 +1242,104 @@ impl BWImage {
         crate::file::encode_file(out, self)
     }
 
-    pub fn iterator<D: IterDirection>(&self, direction: D) -> BWByteIter<D> {
+    /// Iterate the bytes in the order of the direction, call `seekable` on the iterator
+    /// to get the length, iterate from the back or start at any pixel
+    pub fn iterator<D: IterDirection>(&self, direction: D) -> BWByteIter<'_, D> {
         BWByteIter::new(&self.size, &self.pixels, direction)
     }
+
+    /// Iterate the pixels in the order of the direction, returns `(x, y, white)`
+    pub fn pixel_iter<D: PixelDirection>(&self, direction: D) -> BWPixelIter<'_, D> {
+        self.iterator(direction).pixels()
+    }
+
+    /// Iterate the runs of pixels of one color in the rows, returns `(y, x_start, len, white)`
+    pub fn run_iter<D: PixelDirection>(&self, direction: D) -> BWRunIter<'_, D> {
+        self.pixel_iter(direction).runs()
+    }
+
+    /// Get the page addressed buffer of SSD1306 like displays, `width` bytes for every page
+    /// of 8 rows. The rows missing in the last page are black
+    pub fn to_page_buffer(&self) -> Vec<u8> {
+        let mut buf =
+            Vec::with_capacity(self.size.width as usize * self.size.height.div_ceil(8) as usize);
+        buf.extend(
+            self.iterator(iter_direction::Pages)
+                .filter_map(|out| match out {
+                    IterOutput::Byte { byte, .. } => Some(byte),
+                    IterOutput::NewLine => None,
+                }),
+        );
+        buf
+    }
+
+    /// Get the byte index of the pixel and the mask of the pixel in the byte
+    #[inline(always)]
+    fn bit_position(&self, x: u32, y: u32) -> (usize, u8) {
+        (
+            y as usize * self.size.get_padded_row_len() + x as usize / 8,
+            0x80 >> (x % 8),
+        )
+    }
+
+    /// Get the pixel at `(x, y)`, `true` is white, `None` if out of bounds
+    #[inline]
+    pub fn get_checked(&self, x: u32, y: u32) -> Option<bool> {
+        if x >= self.size.width || y >= self.size.height {
+            return None;
+        }
+        let (idx, mask) = self.bit_position(x, y);
+        Some(self.pixels[idx] & mask != 0)
+    }
+
+    /// Set the pixel at `(x, y)`, `true` is white, `None` if out of bounds
+    #[inline]
+    pub fn set_checked(&mut self, x: u32, y: u32, white: bool) -> Option<()> {
+        if x >= self.size.width || y >= self.size.height {
+            return None;
+        }
+        let (idx, mask) = self.bit_position(x, y);
+        if white {
+            self.pixels[idx] |= mask;
+        } else {
+            self.pixels[idx] &= !mask;
+        }
+        Some(())
+    }
+
+    /// Get the pixel at `(x, y)`, `true` is white.
+    /// Panics if the position is out of bounds
+    #[inline]
+    pub fn get(&self, x: u32, y: u32) -> bool {
+        self.get_checked(x, y)
+            .unwrap_or_else(|| self.out_of_bounds(x, y))
+    }
+
+    /// Set the pixel at `(x, y)`, `true` is white.
+    /// Panics if the position is out of bounds
+    #[inline]
+    pub fn set(&mut self, x: u32, y: u32, white: bool) {
+        if self.set_checked(x, y, white).is_none() {
+            self.out_of_bounds(x, y)
+        }
+    }
+
+    /// Set every pixel to white or black, the padding bits of each row are cleared
+    pub fn fill(&mut self, white: bool) {
+        let row_len = self.size.get_padded_row_len();
+        self.pixels.fill(if white { 0xff } else { 0 });
+        if white && !self.size.width.is_multiple_of(8) {
+            let mask = 0xffu8 << (8 - self.size.width % 8);
+            for row in self.pixels.chunks_mut(row_len) {
+                row[row_len - 1] &= mask;
+            }
+        }
+    }
+
+    fn out_of_bounds(&self, x: u32, y: u32) -> ! {
+        panic!(
+            "pixel ({x}, {y}) is out of bounds of {}x{} image",
+            self.size.width, self.size.height
+        )
+    }
 }
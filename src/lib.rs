@@ -1,10 +1,20 @@
+pub mod components;
+pub mod compose;
+pub mod dither;
+pub mod draw;
 pub mod file;
+pub mod font;
 pub mod img;
+pub mod morph;
+pub mod threshold;
+pub mod transform;
+pub mod view;
 
 use std::error::Error;
 
 pub use img::*;
 use thiserror::Error;
+pub use threshold::Threshold;
 
 pub type Result<T> = std::result::Result<T, BWError>;
 
@@ -14,6 +24,8 @@ pub enum BWError {
     Compression(usize, Box<BWError>, u64),
     #[error("err parsing file header: {0}")]
     FileHeader(String),
+    #[error("error parsing font: {0}")]
+    Font(String),
     #[error(transparent)]
     Io(#[from] std::io::Error),
     #[error(transparent)]
@@ -27,8 +39,10 @@ pub enum BWError {
 pub enum BWDataErr {
     #[error("error parsing bw data: {0}")]
     Custom(Box<dyn Error + Send + Sync>),
-    #[error("{0}x{1} is not divisible by 8, got {2} pixels")]
+    #[error("{2} bytes of data is too short for a {0}x{1} image")]
     WrongSize(u32, u32, usize),
+    #[error("row stride {0} is shorter than a row of {1} bytes")]
+    WrongStride(usize, usize),
 }
 
 #[cfg(feature = "video")]
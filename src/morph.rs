@@ -0,0 +1,208 @@
+use crate::{img::BWImageSize, BWImage};
+
+/// Shape probing the image in the morphological operations
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StructuringElement {
+    mask: BWImage,
+    origin: (u32, u32),
+}
+
+impl StructuringElement {
+    /// Element of the white pixels of `mask`, `origin` is the pixel placed on the probed pixel.
+    /// Returns `None` if the origin is out of the mask
+    pub fn new(mask: BWImage, origin: (u32, u32)) -> Option<Self> {
+        (origin.0 < mask.size.width && origin.1 < mask.size.height).then_some(Self { mask, origin })
+    }
+
+    /// Filled `width`x`height` rectangle with the origin in the center
+    pub fn rect(width: u32, height: u32) -> Self {
+        let size = BWImageSize {
+            width: width.max(1),
+            height: height.max(1),
+        };
+        Self {
+            mask: BWImage::new(size, true),
+            origin: (size.width / 2, size.height / 2),
+        }
+    }
+
+    pub fn square(size: u32) -> Self {
+        Self::rect(size, size)
+    }
+
+    /// Plus shape with arms of `radius` pixels
+    pub fn cross(radius: u32) -> Self {
+        let len = radius * 2 + 1;
+        let mut mask = BWImage::new(
+            BWImageSize {
+                width: len,
+                height: len,
+            },
+            false,
+        );
+        for i in 0..len {
+            mask.set(i, radius, true);
+            mask.set(radius, i, true);
+        }
+        Self {
+            mask,
+            origin: (radius, radius),
+        }
+    }
+
+    pub fn mask(&self) -> &BWImage {
+        &self.mask
+    }
+
+    pub fn origin(&self) -> (u32, u32) {
+        self.origin
+    }
+
+    /// Positions of the white pixels of the mask relative to the origin
+    fn offsets(&self) -> impl Iterator<Item = (i64, i64)> + '_ {
+        let size = self.mask.size;
+        (0..size.height)
+            .flat_map(move |y| (0..size.width).map(move |x| (x, y)))
+            .filter(|(x, y)| self.mask.get(*x, *y))
+            .map(|(x, y)| {
+                (
+                    x as i64 - self.origin.0 as i64,
+                    y as i64 - self.origin.1 as i64,
+                )
+            })
+    }
+}
+
+/// Rows of an image packed into 64 bit words, high bit first
+struct WordRows {
+    words: Vec<u64>,
+    row_words: usize,
+}
+
+impl WordRows {
+    /// Pack the rows, the bits past the end of the rows are set to `fill`
+    fn new(img: &BWImage, fill: bool) -> Self {
+        let row_len = img.size.get_padded_row_len();
+        let row_words = img.size.width.div_ceil(64) as usize;
+        let tail = img.size.width % 64;
+        let tail_fill = if fill && tail != 0 {
+            u64::MAX >> tail
+        } else {
+            0
+        };
+
+        let mut words = Vec::with_capacity(row_words * img.size.height as usize);
+        for row in img
+            .pixels
+            .chunks_exact(row_len.max(1))
+            .take(img.size.height as usize)
+        {
+            words.extend(row.chunks(8).map(|chunk| {
+                let mut bytes = [0; 8];
+                bytes[..chunk.len()].copy_from_slice(chunk);
+                u64::from_be_bytes(bytes)
+            }));
+            if let Some(last) = words.last_mut() {
+                *last |= tail_fill;
+            }
+        }
+        Self { words, row_words }
+    }
+
+    fn row(&self, y: usize) -> &[u64] {
+        &self.words[y * self.row_words..(y + 1) * self.row_words]
+    }
+
+    /// Get the word `i` of a row shifted so its pixel `x` is the pixel `x + dx` of the row
+    #[inline(always)]
+    fn shifted(row: &[u64], i: usize, dx: i64, fill: u64) -> u64 {
+        let word = |j: i64| {
+            usize::try_from(j)
+                .ok()
+                .and_then(|j| row.get(j))
+                .copied()
+                .unwrap_or(fill)
+        };
+        let (q, r) = (dx.div_euclid(64), dx.rem_euclid(64) as u32);
+        let j = i as i64 + q;
+        if r == 0 {
+            word(j)
+        } else {
+            (word(j) << r) | (word(j + 1) >> (64 - r))
+        }
+    }
+
+    fn into_image(self, size: BWImageSize) -> BWImage {
+        let row_len = size.get_padded_row_len();
+        let mut pixels = Vec::with_capacity(size.get_padded_bytes_len() as usize);
+        for row in self.words.chunks_exact(self.row_words.max(1)) {
+            let bytes: Vec<u8> = row.iter().flat_map(|w| w.to_be_bytes()).collect();
+            pixels.extend_from_slice(&bytes[..row_len]);
+        }
+        let mut img = BWImage { size, pixels };
+        // clear the padding bits
+        if !size.width.is_multiple_of(8) {
+            let mask = (0xff00u16 >> (size.width % 8)) as u8;
+            for row in img.pixels.chunks_exact_mut(row_len) {
+                row[row_len - 1] &= mask;
+            }
+        }
+        img
+    }
+}
+
+impl BWImage {
+    /// Combine the image shifted by every offset with `and` or or.
+    /// The pixels out of the image are white when combined with and, black otherwise
+    fn combine_shifted(&self, offsets: impl Iterator<Item = (i64, i64)>, and: bool) -> BWImage {
+        let src = WordRows::new(self, and);
+        let fill = if and { u64::MAX } else { 0 };
+        let mut dst = WordRows {
+            words: vec![fill; src.words.len()],
+            row_words: src.row_words,
+        };
+        let height = self.size.height as i64;
+
+        for (dx, dy) in offsets {
+            for y in 0..height {
+                // the rows out of the image don't change the result
+                let sy = y + dy;
+                if sy < 0 || sy >= height {
+                    continue;
+                }
+                let src_row = src.row(sy as usize);
+                let dst_row = &mut dst.words[y as usize * src.row_words..][..src.row_words];
+                for (i, word) in dst_row.iter_mut().enumerate() {
+                    let shifted = WordRows::shifted(src_row, i, dx, fill);
+                    if and {
+                        *word &= shifted;
+                    } else {
+                        *word |= shifted;
+                    }
+                }
+            }
+        }
+        dst.into_image(self.size)
+    }
+
+    /// Shrink the white areas, a pixel stays white if every pixel under the element is white.
+    /// The pixels out of the image count as white
+    pub fn erode(&self, element: &StructuringElement) -> BWImage {
+        self.combine_shifted(element.offsets(), true)
+    }
+
+    /// Grow the white areas, a pixel becomes white if any pixel under the reflected element is white
+    pub fn dilate(&self, element: &StructuringElement) -> BWImage {
+        self.combine_shifted(element.offsets().map(|(x, y)| (-x, -y)), false)
+    }
+
+    /// Erode then dilate, removes the white areas smaller than the element
+    pub fn open(&self, element: &StructuringElement) -> BWImage {
+        self.erode(element).dilate(element)
+    }
+
+    /// Dilate then erode, fills the black holes and gaps smaller than the element
+    pub fn close(&self, element: &StructuringElement) -> BWImage {
+        self.dilate(element).erode(element)
+    }
+}
@@ -0,0 +1,225 @@
+use crate::{img::pack_bw_data, BWDataErr, BWImageSize, ImageData};
+
+/// Strategy used to pick the gray value separating black pixels from white ones
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Threshold {
+    /// Pixels brighter than the value are white
+    Fixed(u8),
+    /// Pick the global threshold with Otsu's method from the luminance histogram of the source
+    Otsu,
+}
+
+impl Default for Threshold {
+    fn default() -> Self {
+        Self::Fixed(128)
+    }
+}
+
+impl From<u8> for Threshold {
+    fn from(value: u8) -> Self {
+        Self::Fixed(value)
+    }
+}
+
+impl Threshold {
+    /// Resolve the threshold from the luminance of every pixel of the source.
+    /// The luminance is only read if the strategy needs it
+    pub fn resolve<I: IntoIterator<Item = u8>>(&self, luma: I) -> u8 {
+        match self {
+            Self::Fixed(threshold) => *threshold,
+            Self::Otsu => otsu_threshold(&histogram(luma)),
+        }
+    }
+}
+
+/// Count the pixels of every luminance value
+pub fn histogram<I: IntoIterator<Item = u8>>(luma: I) -> [u64; 256] {
+    let mut hist = [0u64; 256];
+    for value in luma {
+        hist[value as usize] += 1;
+    }
+    hist
+}
+
+/// Otsu's method: pick the threshold maximizing the between-class variance of the histogram.
+/// Values less than or equal to the returned threshold are the black class
+pub fn otsu_threshold(hist: &[u64; 256]) -> u8 {
+    let total: u64 = hist.iter().sum();
+    let sum: f64 = hist
+        .iter()
+        .enumerate()
+        .map(|(value, count)| value as f64 * *count as f64)
+        .sum();
+
+    let (mut weight_black, mut sum_black) = (0u64, 0f64);
+    let (mut best, mut max_variance) = (0u8, 0f64);
+    for (value, count) in hist.iter().enumerate() {
+        weight_black += count;
+        if weight_black == 0 {
+            continue;
+        }
+        let weight_white = total - weight_black;
+        if weight_white == 0 {
+            break;
+        }
+
+        sum_black += value as f64 * *count as f64;
+        let mean_black = sum_black / weight_black as f64;
+        let mean_white = (sum - sum_black) / weight_white as f64;
+        let variance =
+            weight_black as f64 * weight_white as f64 * (mean_black - mean_white).powi(2);
+        if variance > max_variance {
+            max_variance = variance;
+            best = value as u8;
+        }
+    }
+    best
+}
+
+/// Strategy converting luminance into black and white data
+pub trait Binarize {
+    /// Binarize the luminance of every pixel(row by row without padding) into bw data,
+    /// every row of the output is padded to whole bytes
+    fn binarize(&self, gray: &[u8], size: BWImageSize) -> Vec<u8>;
+}
+
+impl<B: Binarize + ?Sized> Binarize for &B {
+    #[inline(always)]
+    fn binarize(&self, gray: &[u8], size: BWImageSize) -> Vec<u8> {
+        (**self).binarize(gray, size)
+    }
+}
+
+impl<B: Binarize + ?Sized> Binarize for Box<B> {
+    #[inline(always)]
+    fn binarize(&self, gray: &[u8], size: BWImageSize) -> Vec<u8> {
+        (**self).binarize(gray, size)
+    }
+}
+
+impl Binarize for Threshold {
+    fn binarize(&self, gray: &[u8], size: BWImageSize) -> Vec<u8> {
+        let threshold = self.resolve(gray.iter().copied());
+        let width = size.width as usize;
+        pack_bw_data(size, |x, y| {
+            gray[y as usize * width + x as usize] > threshold
+        })
+    }
+}
+
+/// Adaptive binarization, the threshold of every pixel is computed from the window around it
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Adaptive {
+    /// Bradley-Roth: a pixel is black if it's `t` percent darker than the mean of the window
+    Bradley { window: u32, t: f32 },
+    /// Sauvola: the threshold is `mean * (1 + k * (deviation / 128 - 1))` of the window
+    Sauvola { window: u32, k: f32 },
+}
+
+impl Adaptive {
+    pub fn bradley(window: u32) -> Self {
+        Self::Bradley { window, t: 0.15 }
+    }
+
+    pub fn sauvola(window: u32) -> Self {
+        Self::Sauvola { window, k: 0.34 }
+    }
+}
+
+/// Summed-area table, `(width + 1) * (height + 1)` entries with a zero first row and column
+struct IntegralImage {
+    width: usize,
+    table: Vec<u64>,
+}
+
+impl IntegralImage {
+    fn new(size: BWImageSize, value: impl Fn(u8) -> u64, gray: &[u8]) -> Self {
+        let (width, height) = (size.width as usize, size.height as usize);
+        let mut table = vec![0u64; (width + 1) * (height + 1)];
+        for y in 0..height {
+            let mut row_sum = 0;
+            for x in 0..width {
+                row_sum += value(gray[y * width + x]);
+                table[(y + 1) * (width + 1) + x + 1] = table[y * (width + 1) + x + 1] + row_sum;
+            }
+        }
+        Self { width, table }
+    }
+
+    /// Sum of the rectangle from `(x0, y0)` to `(x1, y1)`, exclusive
+    #[inline(always)]
+    fn sum(&self, (x0, y0): (usize, usize), (x1, y1): (usize, usize)) -> u64 {
+        let stride = self.width + 1;
+        self.table[y1 * stride + x1] + self.table[y0 * stride + x0]
+            - self.table[y0 * stride + x1]
+            - self.table[y1 * stride + x0]
+    }
+}
+
+impl Binarize for Adaptive {
+    fn binarize(&self, gray: &[u8], size: BWImageSize) -> Vec<u8> {
+        let (width, height) = (size.width as usize, size.height as usize);
+        let window = match self {
+            Self::Bradley { window, .. } | Self::Sauvola { window, .. } => *window as usize,
+        };
+        let half = window / 2;
+        let bounds = |x: usize, y: usize| {
+            let from = (x.saturating_sub(half), y.saturating_sub(half));
+            let to = ((x + half + 1).min(width), (y + half + 1).min(height));
+            let count = ((to.0 - from.0) * (to.1 - from.1)) as f64;
+            (from, to, count)
+        };
+
+        let sums = IntegralImage::new(size, u64::from, gray);
+        match *self {
+            Self::Bradley { t, .. } => pack_bw_data(size, |x, y| {
+                let (x, y) = (x as usize, y as usize);
+                let (from, to, count) = bounds(x, y);
+                gray[y * width + x] as f64 * count > sums.sum(from, to) as f64 * (1.0 - t as f64)
+            }),
+            Self::Sauvola { k, .. } => {
+                let squares = IntegralImage::new(size, |v| (v as u64).pow(2), gray);
+                pack_bw_data(size, |x, y| {
+                    let (x, y) = (x as usize, y as usize);
+                    let (from, to, count) = bounds(x, y);
+                    let mean = sums.sum(from, to) as f64 / count;
+                    let variance = squares.sum(from, to) as f64 / count - mean * mean;
+                    let deviation = variance.max(0.0).sqrt();
+                    let threshold = mean * (1.0 + k as f64 * (deviation / 128.0 - 1.0));
+                    gray[y * width + x] as f64 > threshold
+                })
+            }
+        }
+    }
+}
+
+/// Image data binarized with a custom strategy
+pub struct Binarized<'a, T: ImageData, B: Binarize> {
+    data: &'a T,
+    binarizer: B,
+}
+
+impl<'a, T: ImageData, B: Binarize> Binarized<'a, T, B> {
+    pub fn new(data: &'a T, binarizer: B) -> Self {
+        Self { data, binarizer }
+    }
+}
+
+impl<T: ImageData, B: Binarize> ImageData for Binarized<'_, T, B> {
+    fn to_bw_data(&self) -> Result<Vec<u8>, BWDataErr> {
+        let size = self.data.image_config();
+        let gray = self.data.to_gray_data()?;
+        if gray.len() < size.width as usize * size.height as usize {
+            return Err(BWDataErr::WrongSize(size.width, size.height, gray.len()));
+        }
+        Ok(self.binarizer.binarize(&gray, size))
+    }
+
+    fn image_config(&self) -> BWImageSize {
+        self.data.image_config()
+    }
+
+    fn to_gray_data(&self) -> Result<Vec<u8>, BWDataErr> {
+        self.data.to_gray_data()
+    }
+}
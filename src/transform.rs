@@ -0,0 +1,230 @@
+use crate::{img::fill_bits, BWImage, BWImageSize};
+
+/// Transpose a 8x8 bit block, byte `i` from the high byte is the row `i`, high bit first
+#[inline(always)]
+fn transpose_block(mut x: u64) -> u64 {
+    let mut t = (x ^ (x >> 7)) & 0x00AA_00AA_00AA_00AA;
+    x ^= t ^ (t << 7);
+    t = (x ^ (x >> 14)) & 0x0000_CCCC_0000_CCCC;
+    x ^= t ^ (t << 14);
+    t = (x ^ (x >> 28)) & 0x0000_0000_F0F0_F0F0;
+    x ^= t ^ (t << 28);
+    x
+}
+
+impl BWImage {
+    /// Swap the rows and columns, the pixel `(x, y)` moves to `(y, x)`
+    pub fn transpose(&self) -> BWImage {
+        let size = BWImageSize {
+            width: self.size.height,
+            height: self.size.width,
+        };
+        let (src_row_len, dst_row_len) =
+            (self.size.get_padded_row_len(), size.get_padded_row_len());
+        let mut pixels = vec![0u8; size.get_padded_bytes_len() as usize];
+
+        for block_y in 0..self.size.height.div_ceil(8) as usize {
+            for block_x in 0..src_row_len {
+                let mut block = 0u64;
+                for i in 0..8 {
+                    let y = block_y * 8 + i;
+                    if y < self.size.height as usize {
+                        block |= (self.pixels[y * src_row_len + block_x] as u64) << (56 - i * 8);
+                    }
+                }
+                if block == 0 {
+                    continue;
+                }
+
+                let block = transpose_block(block);
+                for i in 0..8 {
+                    let y = block_x * 8 + i;
+                    if y < size.height as usize {
+                        pixels[y * dst_row_len + block_y] = (block >> (56 - i * 8)) as u8;
+                    }
+                }
+            }
+        }
+
+        BWImage { size, pixels }
+    }
+
+    /// Mirror the image left to right
+    pub fn flip_horizontal(&self) -> BWImage {
+        let row_len = self.size.get_padded_row_len();
+        let pad = (row_len * 8 - self.size.width as usize) as u32;
+        let mut pixels = Vec::with_capacity(self.pixels.len());
+        let mut reversed = vec![0u8; row_len];
+
+        for row in self
+            .pixels
+            .chunks(row_len.max(1))
+            .take(self.size.height as usize)
+        {
+            for (dst, src) in reversed.iter_mut().zip(row.iter().rev()) {
+                *dst = src.reverse_bits();
+            }
+            // the padding bits are at the start of the row now, shift them out
+            for i in 0..row_len {
+                let next = reversed.get(i + 1).copied().unwrap_or(0);
+                pixels.push(match pad {
+                    0 => reversed[i],
+                    pad => (reversed[i] << pad) | (next >> (8 - pad)),
+                });
+            }
+        }
+
+        BWImage {
+            size: self.size,
+            pixels,
+        }
+    }
+
+    /// Mirror the image top to bottom
+    pub fn flip_vertical(&self) -> BWImage {
+        let row_len = self.size.get_padded_row_len();
+        BWImage {
+            size: self.size,
+            pixels: self
+                .pixels
+                .chunks(row_len.max(1))
+                .take(self.size.height as usize)
+                .rev()
+                .flatten()
+                .copied()
+                .collect(),
+        }
+    }
+
+    /// Rotate the image 90 degrees clockwise
+    pub fn rotate_90(&self) -> BWImage {
+        self.transpose().flip_horizontal()
+    }
+
+    pub fn rotate_180(&self) -> BWImage {
+        self.flip_horizontal().flip_vertical()
+    }
+
+    /// Rotate the image 90 degrees counterclockwise
+    pub fn rotate_270(&self) -> BWImage {
+        self.transpose().flip_vertical()
+    }
+}
+
+/// Count the white pixels from `from` to `to`(exclusive) of a row
+fn count_bits(row: &[u8], from: usize, to: usize) -> u32 {
+    row.iter()
+        .enumerate()
+        .take(to.div_ceil(8))
+        .skip(from / 8)
+        .map(|(i, byte)| {
+            let start = from.max(i * 8) - i * 8;
+            let end = to.min(i * 8 + 8) - i * 8;
+            (byte & (0xffu8 >> start) & !(0xffu16 >> end) as u8).count_ones()
+        })
+        .sum()
+}
+
+impl BWImage {
+    /// Scale the image to `size` with nearest neighbour sampling
+    pub fn resize(&self, size: BWImageSize) -> BWImage {
+        let (src_row_len, row_len) = (self.size.get_padded_row_len(), size.get_padded_row_len());
+        let mut pixels = vec![0u8; size.get_padded_bytes_len() as usize];
+        if self.size.width == 0 || self.size.height == 0 {
+            return BWImage { size, pixels };
+        }
+
+        let src_xs = (0..size.width as u64)
+            .map(|x| (x * self.size.width as u64 / size.width as u64) as usize)
+            .collect::<Vec<_>>();
+        let mut last_src_y = None;
+        for y in 0..size.height as usize {
+            let src_y = (y as u64 * self.size.height as u64 / size.height as u64) as usize;
+            let (done, rest) = pixels.split_at_mut(y * row_len);
+            let row = &mut rest[..row_len];
+            if last_src_y == Some(src_y) {
+                // same source row, copy the previous output row
+                row.copy_from_slice(&done[(y - 1) * row_len..]);
+                continue;
+            }
+
+            let src = &self.pixels[src_y * src_row_len..(src_y + 1) * src_row_len];
+            for (x, src_x) in src_xs.iter().enumerate() {
+                if src[src_x / 8] & (0x80 >> (src_x % 8)) != 0 {
+                    row[x / 8] |= 0x80 >> (x % 8);
+                }
+            }
+            last_src_y = Some(src_y);
+        }
+
+        BWImage { size, pixels }
+    }
+
+    /// Scale the image up by an integer factor, every pixel becomes a `factor`x`factor` block.
+    /// Returns `None` if the scaled size overflows `u32`
+    pub fn scale_up(&self, factor: u32) -> Option<BWImage> {
+        let factor = factor.max(1);
+        let size = BWImageSize {
+            width: self.size.width.checked_mul(factor)?,
+            height: self.size.height.checked_mul(factor)?,
+        };
+        let (src_row_len, row_len) = (self.size.get_padded_row_len(), size.get_padded_row_len());
+        let mut pixels = Vec::with_capacity(size.get_padded_bytes_len() as usize);
+        let mut row = vec![0u8; row_len];
+
+        for src in self
+            .pixels
+            .chunks(src_row_len.max(1))
+            .take(self.size.height as usize)
+        {
+            row.fill(0);
+            for x in 0..self.size.width as usize {
+                if src[x / 8] & (0x80 >> (x % 8)) != 0 {
+                    let from = x * factor as usize;
+                    fill_bits(&mut row, from, from + factor as usize, true);
+                }
+            }
+            for _ in 0..factor {
+                pixels.extend_from_slice(&row);
+            }
+        }
+
+        Some(BWImage { size, pixels })
+    }
+
+    /// Scale the image down by an integer factor, every `factor`x`factor` block becomes
+    /// a white pixel if more than half of its pixels are white.
+    /// The blocks at the right and bottom edges may be smaller
+    pub fn scale_down(&self, factor: u32) -> BWImage {
+        let factor = factor.max(1) as usize;
+        let size = BWImageSize {
+            width: self.size.width.div_ceil(factor as u32),
+            height: self.size.height.div_ceil(factor as u32),
+        };
+        let (width, height) = (self.size.width as usize, self.size.height as usize);
+        let (src_row_len, row_len) = (self.size.get_padded_row_len(), size.get_padded_row_len());
+        let mut pixels = vec![0u8; size.get_padded_bytes_len() as usize];
+        let mut counts = vec![0u32; size.width as usize];
+
+        for y in 0..size.height as usize {
+            let rows = (y * factor)..((y + 1) * factor).min(height);
+            let block_height = rows.len();
+            counts.fill(0);
+            for src_y in rows {
+                let src = &self.pixels[src_y * src_row_len..(src_y + 1) * src_row_len];
+                for (x, count) in counts.iter_mut().enumerate() {
+                    *count += count_bits(src, x * factor, ((x + 1) * factor).min(width));
+                }
+            }
+
+            for (x, count) in counts.iter().enumerate() {
+                let block_width = ((x + 1) * factor).min(width) - x * factor;
+                if *count as usize * 2 > block_width * block_height {
+                    pixels[y * row_len + x / 8] |= 0x80 >> (x % 8);
+                }
+            }
+        }
+
+        BWImage { size, pixels }
+    }
+}
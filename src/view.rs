@@ -0,0 +1,268 @@
+use crate::{compose::RasterOp, BWByteIter, BWImage, BWImageSize, BWIterState, IterDirection};
+
+/// Position of an area placed in a larger one
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Alignment {
+    #[default]
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    Center,
+    Right,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+}
+
+impl Alignment {
+    /// Get the position of an `inner` area aligned in the `outer` one.
+    /// The position is negative if the inner area is larger
+    pub fn offset(&self, inner: BWImageSize, outer: BWImageSize) -> (i64, i64) {
+        let (dx, dy) = (
+            outer.width as i64 - inner.width as i64,
+            outer.height as i64 - inner.height as i64,
+        );
+        let x = match self {
+            Self::TopLeft | Self::Left | Self::BottomLeft => 0,
+            Self::Top | Self::Center | Self::Bottom => dx / 2,
+            Self::TopRight | Self::Right | Self::BottomRight => dx,
+        };
+        let y = match self {
+            Self::TopLeft | Self::Top | Self::TopRight => 0,
+            Self::Left | Self::Center | Self::Right => dy / 2,
+            Self::BottomLeft | Self::Bottom | Self::BottomRight => dy,
+        };
+        (x, y)
+    }
+}
+
+/// Borrowed rectangular area of a `BWImage`, no pixels are copied
+#[derive(Clone, Copy, Debug)]
+pub struct BWView<'a> {
+    img: &'a BWImage,
+    origin: (u32, u32),
+    size: BWImageSize,
+}
+
+impl<'a> BWView<'a> {
+    #[inline(always)]
+    pub fn size(&self) -> BWImageSize {
+        self.size
+    }
+
+    /// Position of the view in the image
+    #[inline(always)]
+    pub fn origin(&self) -> (u32, u32) {
+        self.origin
+    }
+
+    /// Get the pixel at `(x, y)` of the view, `None` if out of bounds
+    pub fn get_checked(&self, x: u32, y: u32) -> Option<bool> {
+        if x >= self.size.width || y >= self.size.height {
+            return None;
+        }
+        Some(self.state().get(x, y))
+    }
+
+    /// Get the pixel at `(x, y)` of the view.
+    /// Panics if the position is out of bounds
+    pub fn get(&self, x: u32, y: u32) -> bool {
+        self.get_checked(x, y).unwrap_or_else(|| {
+            panic!(
+                "pixel ({x}, {y}) is out of bounds of {}x{} view",
+                self.size.width, self.size.height
+            )
+        })
+    }
+
+    /// Get a view of an area in this view, the area is clipped to this view
+    pub fn view(&self, x: u32, y: u32, width: u32, height: u32) -> BWView<'a> {
+        let (x, y) = (x.min(self.size.width), y.min(self.size.height));
+        BWView {
+            img: self.img,
+            origin: (self.origin.0 + x, self.origin.1 + y),
+            size: BWImageSize {
+                width: width.min(self.size.width - x),
+                height: height.min(self.size.height - y),
+            },
+        }
+    }
+
+    pub fn iterator<D: IterDirection>(&self, direction: D) -> BWByteIter<'a, D> {
+        BWByteIter::with_area(
+            &self.size,
+            &self.img.pixels,
+            self.origin,
+            self.img.size.get_padded_row_len(),
+            direction,
+        )
+    }
+
+    /// Copy the pixels of the view into a new image
+    pub fn to_image(&self) -> BWImage {
+        let mut img = BWImage::new(self.size, false);
+        img.copy_view(self, 0, 0);
+        img
+    }
+
+    fn state(&self) -> BWIterState<'a> {
+        BWIterState {
+            size: self.size,
+            current: (0, 0),
+            pixels: &self.img.pixels,
+            origin: self.origin,
+            row_len: self.img.size.get_padded_row_len(),
+        }
+    }
+}
+
+impl BWImage {
+    /// Get a view of an area of the image, the area is clipped to the image
+    pub fn view(&self, x: u32, y: u32, width: u32, height: u32) -> BWView<'_> {
+        self.as_view().view(x, y, width, height)
+    }
+
+    /// Get a view of the whole image
+    pub fn as_view(&self) -> BWView<'_> {
+        BWView {
+            img: self,
+            origin: (0, 0),
+            size: self.size,
+        }
+    }
+
+    /// Copy an area of the image, the area is clipped to the image
+    pub fn crop(&self, x: u32, y: u32, width: u32, height: u32) -> BWImage {
+        self.view(x, y, width, height).to_image()
+    }
+
+    /// Place the image in a new image of `size` filled with white or black.
+    /// The pixels out of the new image are dropped
+    pub fn extend(&self, size: BWImageSize, align: Alignment, white: bool) -> BWImage {
+        let (x, y) = align.offset(self.size, size);
+        let (src_x, src_y) = ((-x).max(0) as u32, (-y).max(0) as u32);
+        let (dst_x, dst_y) = (x.max(0) as u32, y.max(0) as u32);
+        let view = self.view(
+            src_x,
+            src_y,
+            size.width.saturating_sub(dst_x),
+            size.height.saturating_sub(dst_y),
+        );
+
+        let mut img = BWImage::new(size, white);
+        img.copy_view(&view, dst_x, dst_y);
+        img
+    }
+
+    /// Add margins of white or black pixels around the image.
+    /// Returns `None` if the padded size overflows `u32`
+    pub fn pad(
+        &self,
+        left: u32,
+        top: u32,
+        right: u32,
+        bottom: u32,
+        white: bool,
+    ) -> Option<BWImage> {
+        let size = BWImageSize {
+            width: left.checked_add(self.size.width)?.checked_add(right)?,
+            height: top.checked_add(self.size.height)?.checked_add(bottom)?,
+        };
+        let mut img = BWImage::new(size, white);
+        img.copy_view(&self.as_view(), left, top);
+        Some(img)
+    }
+
+    /// Extend the width and height to the next multiple, e.g. 8 for byte aligned rows.
+    /// Returns `None` if the extended size overflows `u32`
+    pub fn pad_to_multiple(&self, multiple: u32, align: Alignment, white: bool) -> Option<BWImage> {
+        let multiple = multiple.max(1);
+        let size = BWImageSize {
+            width: self.size.width.div_ceil(multiple).checked_mul(multiple)?,
+            height: self.size.height.div_ceil(multiple).checked_mul(multiple)?,
+        };
+        Some(self.extend(size, align, white))
+    }
+
+    /// Get the tight bounding box of the white pixels, or of the black ones if `white` is false.
+    /// Returns `None` if there are no such pixels
+    pub fn content_bounds(&self, white: bool) -> Option<((u32, u32), BWImageSize)> {
+        let row_len = self.size.get_padded_row_len();
+        if row_len == 0 {
+            return None;
+        }
+        let last_mask = 0xffu8 << ((8 - self.size.width % 8) % 8);
+
+        // left, top, right and bottom, inclusive
+        let mut bounds: Option<(u32, u32, u32, u32)> = None;
+        for (y, row) in self
+            .pixels
+            .chunks_exact(row_len)
+            .take(self.size.height as usize)
+            .enumerate()
+        {
+            let bits = |i: usize| {
+                let byte = if white { row[i] } else { !row[i] };
+                if i == row_len - 1 {
+                    byte & last_mask
+                } else {
+                    byte
+                }
+            };
+            let Some(first) = (0..row_len).find(|i| bits(*i) != 0) else {
+                continue;
+            };
+            let last = (first..row_len)
+                .rev()
+                .find(|i| bits(*i) != 0)
+                .unwrap_or(first);
+            let left = (first * 8) as u32 + bits(first).leading_zeros();
+            let right = (last * 8 + 7) as u32 - bits(last).trailing_zeros();
+            let y = y as u32;
+            bounds = Some(match bounds {
+                Some((l, t, r, _)) => (l.min(left), t, r.max(right), y),
+                None => (left, y, right, y),
+            });
+        }
+
+        bounds.map(|(l, t, r, b)| {
+            (
+                (l, t),
+                BWImageSize {
+                    width: r - l + 1,
+                    height: b - t + 1,
+                },
+            )
+        })
+    }
+
+    /// Crop the margins without white pixels(or black ones if `white` is false), keeping
+    /// up to `padding` pixels of margin around the content. `None` if the image has no content
+    pub fn trim(&self, white: bool, padding: u32) -> Option<BWImage> {
+        let ((x, y), size) = self.content_bounds(white)?;
+        let (left, top) = (x.saturating_sub(padding), y.saturating_sub(padding));
+        Some(self.crop(
+            left,
+            top,
+            (x + size.width).saturating_add(padding) - left,
+            (y + size.height).saturating_add(padding) - top,
+        ))
+    }
+
+    /// Copy the pixels of a view to `(x, y)`, the view must fit in the image
+    pub(crate) fn copy_view(&mut self, view: &BWView, x: u32, y: u32) {
+        self.apply_view(view, x, y, RasterOp::Copy);
+    }
+
+    /// Combine the pixels of a view with the pixels at `(x, y)`, the view must fit in the image
+    pub(crate) fn apply_view(&mut self, view: &BWView, x: u32, y: u32, op: RasterOp) {
+        let state = view.state();
+        for row in 0..view.size.height {
+            for col in (0..view.size.width).step_by(8) {
+                let (byte, len) = state.row_byte(col, row);
+                self.apply_bits(x + col, y + row, byte, len, op);
+            }
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use bw_img::{
+    components::{Component, Connectivity},
+    draw::Paint,
+    img::BWImageSize,
+    BWImage,
+};
+
+fn canvas() -> BWImage {
+    let mut img = BWImage::new(
+        BWImageSize {
+            width: 20,
+            height: 10,
+        },
+        false,
+    );
+    // 3x3 block, diagonal pair and a single pixel
+    img.fill_rect((1, 1), 3, 3, Paint::White);
+    img.set(10, 2, true);
+    img.set(11, 3, true);
+    img.set(18, 8, true);
+    img
+}
+
+#[test]
+fn labeling() {
+    let img = canvas();
+    let eight = img.components(true, Connectivity::Eight);
+    assert_eq!(eight.components.len(), 3);
+    assert_eq!(
+        eight.components[0],
+        Component {
+            origin: (1, 1),
+            size: BWImageSize {
+                width: 3,
+                height: 3
+            },
+            pixels: 9,
+            centroid: (2.0, 2.0),
+        }
+    );
+    assert_eq!(eight.components[1].pixels, 2);
+    assert_eq!(eight.components[1].centroid, (10.5, 2.5));
+    assert_eq!(eight.label(11, 3), Some(1));
+    assert_eq!(eight.label(18, 8), Some(2));
+    assert_eq!(eight.label(0, 0), None);
+    assert_eq!(eight.label(20, 0), None);
+
+    let four = img.components(true, Connectivity::Four);
+    assert_eq!(four.components.len(), 4);
+    assert_ne!(four.label(10, 2), four.label(11, 3));
+
+    // the black background is a single component with a hole in the block
+    let background = img.components(false, Connectivity::Four);
+    assert_eq!(background.components.len(), 1);
+    assert_eq!(background.components[0].pixels, 200 - 9 - 2 - 1);
+}
+
+#[test]
+fn merging_labels() {
+    // a U shape gets two provisional labels merged at the bottom
+    let mut img = BWImage::new(
+        BWImageSize {
+            width: 7,
+            height: 4,
+        },
+        false,
+    );
+    img.fill_rect((0, 0), 1, 4, Paint::White);
+    img.fill_rect((6, 0), 1, 4, Paint::White);
+    img.fill_rect((0, 3), 7, 1, Paint::White);
+    img.set(3, 0, true);
+    let components = img.components(true, Connectivity::Four);
+    assert_eq!(components.components.len(), 2);
+    assert_eq!(components.components[0].pixels, 4 + 4 + 5);
+    assert_eq!(components.label(6, 0), Some(0));
+    assert_eq!(components.label(3, 0), Some(1));
+
+    let mask = components.mask(|c| c.pixels > 1);
+    assert!(mask.get(6, 0) && !mask.get(3, 0));
+}
+
+#[test]
+fn despeckle() {
+    let img = canvas();
+    let clean = img.despeckle(true, Connectivity::Eight, 2);
+    assert!(!clean.get(18, 8));
+    assert!(clean.get(10, 2) && clean.get(2, 2));
+    assert_eq!(
+        img.despeckle(true, Connectivity::Eight, 3)
+            .components(true, Connectivity::Eight)
+            .components
+            .len(),
+        1
+    );
+
+    // black speckles on white
+    let mut img = BWImage::new(img.size, true);
+    img.set(5, 5, false);
+    assert_eq!(
+        img.despeckle(false, Connectivity::Four, 2),
+        BWImage::new(img.size, true)
+    );
+}
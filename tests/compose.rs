@@ -0,0 +1,78 @@
+use bw_img::{compose::RasterOp, img::BWImageSize, BWImage, NormalImage};
+
+static RUST: &[u8] = include_bytes!("../assets/rust.png");
+
+fn pattern(width: u32, height: u32, seed: u32) -> BWImage {
+    let mut img = BWImage::new(BWImageSize { width, height }, false);
+    for y in 0..height {
+        for x in 0..width {
+            img.set(x, y, (x * 7 + y * 13 + seed).is_multiple_of(3));
+        }
+    }
+    img
+}
+
+#[test]
+fn blit_raster_ops() {
+    let dst = pattern(37, 19, 0);
+    let src = pattern(13, 9, 1);
+
+    for op in [
+        RasterOp::Copy,
+        RasterOp::And,
+        RasterOp::Or,
+        RasterOp::Xor,
+        RasterOp::AndNot,
+    ] {
+        for (ox, oy) in [
+            (0, 0),
+            (3, 5),
+            (11, 2),
+            (-5, -3),
+            (30, 15),
+            (-13, 0),
+            (-12, -8),
+            (37, 0),
+        ] {
+            let out = dst.compose(&src, ox, oy, op);
+            for y in 0..dst.size.height {
+                for x in 0..dst.size.width {
+                    let (sx, sy) = (x as i64 - ox, y as i64 - oy);
+                    let inside = (0..src.size.width as i64).contains(&sx)
+                        && (0..src.size.height as i64).contains(&sy);
+                    let expected = if inside {
+                        let (d, s) = (dst.get(x, y) as u8, src.get(sx as u32, sy as u32) as u8);
+                        op.apply(d, s) & 1 != 0
+                    } else {
+                        dst.get(x, y)
+                    };
+                    assert_eq!(
+                        out.get(x, y),
+                        expected,
+                        "{op:?} at ({ox}, {oy}): ({x}, {y})"
+                    );
+                }
+            }
+            // padding bits are never touched
+            assert_eq!(out.pixels[4] & 0b0000_0111, 0);
+        }
+        for (ox, oy) in [(i64::MIN, 0), (0, i64::MIN), (i64::MAX, i64::MAX)] {
+            assert_eq!(dst.compose(&src, ox, oy, op), dst);
+        }
+    }
+}
+
+#[test]
+fn blit_view() {
+    let rust = BWImage::parse(&NormalImage::new(&image::load_from_memory(RUST).unwrap())).unwrap();
+    let mut canvas = BWImage::new(rust.size, false);
+    canvas.blit_view(&rust.view(10, 10, 20, 20), 10, 10, RasterOp::Copy);
+    canvas.blit_view(&rust.view(30, 10, 34, 54), 30, 10, RasterOp::Or);
+    canvas.blit_view(&rust.view(0, 0, 64, 10), 0, 0, RasterOp::Copy);
+    canvas.blit_view(&rust.view(0, 10, 10, 54), 0, 10, RasterOp::Xor);
+    canvas.blit(&rust.crop(10, 30, 20, 34), 10, 30, RasterOp::Copy);
+    assert_eq!(canvas.pixels, rust.pixels);
+
+    canvas.blit(&rust, 0, 0, RasterOp::AndNot);
+    assert!(canvas.pixels.iter().all(|b| *b == 0));
+}
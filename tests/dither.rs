@@ -0,0 +1,105 @@
+use bw_img::{
+    dither::{DiffusionKernel, ErrorDiffusion, OrderedDither, ThresholdMap},
+    BWImage, ImageData, NormalImage, RgbData,
+};
+
+static RUST: &[u8] = include_bytes!("../assets/rust.png");
+
+fn white_ratio(img: &BWImage) -> f64 {
+    let white: u32 = img.pixels.iter().map(|b| b.count_ones()).sum();
+    white as f64 / (img.size.width * img.size.height) as f64
+}
+
+#[test]
+fn error_diffusion_keeps_tone() {
+    let kernels = [
+        DiffusionKernel::FloydSteinberg,
+        DiffusionKernel::Atkinson,
+        DiffusionKernel::JarvisJudiceNinke,
+        DiffusionKernel::Stucki,
+        DiffusionKernel::Sierra,
+    ];
+
+    for value in [0u8, 64, 128, 191, 255] {
+        let data = vec![value; 32 * 32 * 3];
+        let rgb = RgbData::new(&data, 32, 32);
+        for kernel in kernels {
+            for dither in [
+                ErrorDiffusion::new(kernel),
+                ErrorDiffusion::serpentine(kernel),
+            ] {
+                let img = rgb.binarize_with(dither).parse_bw_image().unwrap();
+                let ratio = white_ratio(&img);
+                // atkinson drops a quarter of the error, midtones drift a bit more
+                let tolerance = if kernel == DiffusionKernel::Atkinson {
+                    0.1
+                } else {
+                    0.05
+                };
+                assert!(
+                    (ratio - value as f64 / 255.0).abs() < tolerance,
+                    "{dither:?} of {value}: {ratio}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn error_diffusion_normal_image() {
+    let img = image::load_from_memory(RUST).unwrap();
+    let dithered = NormalImage::new(&img)
+        .binarize_with(ErrorDiffusion::default())
+        .parse_bw_image()
+        .unwrap();
+    assert_eq!(dithered.size, NormalImage::new(&img).image_config());
+    assert_eq!(
+        dithered.size.get_padded_bytes_len(),
+        dithered.pixels.len() as u64
+    );
+}
+
+#[test]
+fn ordered_dither() {
+    let bayer = ThresholdMap::bayer(2).unwrap();
+    assert_eq!(
+        [
+            bayer.get(0, 0),
+            bayer.get(1, 0),
+            bayer.get(0, 1),
+            bayer.get(1, 1)
+        ],
+        [32, 160, 224, 96]
+    );
+    assert!(ThresholdMap::bayer(3).is_none());
+    assert!(ThresholdMap::new(2, 2, vec![0; 3]).is_none());
+
+    for size in [2, 4, 8, 16] {
+        let map = ThresholdMap::bayer(size).unwrap();
+        let mut values = (0..size * size)
+            .map(|i| map.get(i % size, i / size))
+            .collect::<Vec<_>>();
+        values.sort();
+        values.dedup();
+        assert_eq!(values.len() as u32, size * size);
+
+        let data = vec![100u8; 48 * 48 * 3];
+        let rgb = RgbData::new(&data, 48, 48);
+        let dither = OrderedDither::bayer(size).unwrap();
+        let first = rgb.binarize_with(&dither).parse_bw_image().unwrap();
+        // a n*n matrix only has n*n + 1 tone levels
+        let tolerance = 1.0 / (size * size) as f64;
+        assert!((white_ratio(&first) - 100.0 / 255.0).abs() <= tolerance);
+        // same input, same output: no flickering between frames
+        let second = rgb.binarize_with(&dither).parse_bw_image().unwrap();
+        assert_eq!(first.pixels, second.pixels);
+    }
+
+    let custom = OrderedDither::new(ThresholdMap::new(2, 1, vec![50, 150]).unwrap());
+    let data = vec![100u8; 8 * 3];
+    let img = RgbData::new(&data, 8, 1)
+        .binarize_with(custom)
+        .parse_bw_image()
+        .unwrap();
+    assert_eq!(img.pixels, vec![0b1010_1010]);
+}
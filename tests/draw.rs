@@ -0,0 +1,182 @@
+use bw_img::{compose::RasterOp, draw::Paint, img::BWImageSize, BWImage};
+
+fn canvas(width: u32, height: u32) -> BWImage {
+    BWImage::new(BWImageSize { width, height }, false)
+}
+
+fn white_pixels(img: &BWImage) -> Vec<(u32, u32)> {
+    (0..img.size.height)
+        .flat_map(|y| (0..img.size.width).map(move |x| (x, y)))
+        .filter(|(x, y)| img.get(*x, *y))
+        .collect()
+}
+
+#[test]
+fn lines_and_rects() {
+    let mut img = canvas(20, 10);
+    img.draw_line((0, 0), (19, 9), Paint::White);
+    let pixels = white_pixels(&img);
+    assert_eq!(pixels.len(), 20);
+    assert!(pixels.contains(&(0, 0)) && pixels.contains(&(19, 9)));
+
+    // clipped at the edges
+    let mut img = canvas(20, 10);
+    img.draw_line((-5, 3), (30, 3), Paint::White);
+    assert_eq!(
+        white_pixels(&img),
+        (0..20).map(|x| (x, 3)).collect::<Vec<_>>()
+    );
+
+    // far off the canvas, only the visible part is walked
+    let mut img = canvas(20, 10);
+    img.draw_line((i32::MIN, 0), (i32::MAX, 0), Paint::White);
+    assert_eq!(
+        white_pixels(&img),
+        (0..20).map(|x| (x, 0)).collect::<Vec<_>>()
+    );
+    let mut img = canvas(20, 10);
+    img.draw_line((i32::MIN, i32::MIN), (i32::MAX, i32::MAX), Paint::White);
+    assert_eq!(
+        white_pixels(&img),
+        (0..10).map(|i| (i, i)).collect::<Vec<_>>()
+    );
+    img.draw_line((-10, -10), (-1, 20), Paint::Invert);
+    assert_eq!(white_pixels(&img).len(), 10);
+    let mut img = canvas(20, 10);
+    img.fill_rect((0, 0), 1, u32::MAX, Paint::White);
+    img.draw_rect((5, -5), 3, u32::MAX, Paint::White);
+    assert_eq!(white_pixels(&img).len(), 10 * 3);
+
+    let mut img = canvas(20, 10);
+    img.fill_rect((3, 2), 13, 5, Paint::White);
+    assert_eq!(white_pixels(&img).len(), 13 * 5);
+    img.fill_rect((5, 3), 3, 2, Paint::Black);
+    assert_eq!(white_pixels(&img).len(), 13 * 5 - 6);
+
+    let mut img = canvas(20, 10);
+    img.draw_rect((2, 1), 10, 6, Paint::White);
+    assert_eq!(white_pixels(&img).len(), 2 * 10 + 2 * 4);
+    assert!(img.get(2, 1) && img.get(11, 6) && !img.get(3, 2));
+
+    let mut polygon = canvas(20, 10);
+    polygon.fill_polygon(&[(3, 2), (16, 2), (16, 7), (3, 7)], Paint::White);
+    let mut rect = canvas(20, 10);
+    rect.fill_rect((3, 2), 13, 5, Paint::White);
+    assert_eq!(polygon.pixels, rect.pixels);
+    // edges spanning the whole i32 range
+    let mut polygon = canvas(20, 10);
+    polygon.fill_polygon(&[(i32::MIN, 0), (i32::MAX, 5), (0, 10)], Paint::White);
+    assert_eq!(
+        white_pixels(&polygon),
+        (3..10)
+            .flat_map(|y| (0..20).map(move |x| (x, y)))
+            .collect::<Vec<_>>()
+    );
+
+    let mut outline = canvas(20, 10);
+    outline.draw_polygon(&[(2, 1), (11, 1), (11, 6), (2, 6)], Paint::White);
+    let mut rect = canvas(20, 10);
+    rect.draw_rect((2, 1), 10, 6, Paint::White);
+    assert_eq!(outline.pixels, rect.pixels);
+}
+
+#[test]
+fn circles_and_ellipses() {
+    let mut img = canvas(41, 41);
+    img.fill_circle((20, 20), 15, Paint::White);
+    let area = white_pixels(&img).len() as f64;
+    let expected = std::f64::consts::PI * 15.0 * 15.0;
+    assert!((area - expected).abs() / expected < 0.1, "{area}");
+    for (x, y) in white_pixels(&img) {
+        // symmetric around the center
+        assert!(img.get(40 - x, y) && img.get(x, 40 - y) && img.get(y, x));
+    }
+
+    let mut outline = canvas(41, 41);
+    outline.draw_circle((20, 20), 15, Paint::White);
+    assert!(outline.get(5, 20) && outline.get(35, 20) && outline.get(20, 5));
+    assert!(!outline.get(20, 20));
+    for (x, y) in white_pixels(&outline) {
+        assert!(img.get(x, y));
+    }
+
+    let mut ellipse = canvas(41, 21);
+    ellipse.fill_ellipse((20, 10), 18, 6, Paint::White);
+    assert!(ellipse.get(2, 10) && ellipse.get(38, 10) && ellipse.get(20, 4));
+    assert!(!ellipse.get(1, 10) && !ellipse.get(20, 3));
+
+    // large radii only visit the rows of the image
+    let mut img = canvas(20, 20);
+    img.draw_circle((0, 0), 50_000, Paint::White);
+    assert!(white_pixels(&img).is_empty());
+    img.draw_circle((10, 50_000), 50_000, Paint::White);
+    let mut top = vec![(10, 0)];
+    top.extend((0..20).filter(|x| *x != 10).map(|x| (x, 1)));
+    assert_eq!(white_pixels(&img), top);
+    let mut img = canvas(20, 20);
+    img.fill_circle((10, 50_000), 50_000, Paint::White);
+    assert_eq!(white_pixels(&img).len(), 381);
+
+    let mut img = canvas(20, 20);
+    img.draw_circle((10, 10), u32::MAX, Paint::White);
+    assert!(white_pixels(&img).is_empty());
+    img.fill_circle((10, 10), u32::MAX, Paint::White);
+    assert_eq!(white_pixels(&img).len(), 400);
+    let mut img = canvas(20, 20);
+    img.draw_ellipse((10, 10), u32::MAX, 3, Paint::White);
+    let pixels = white_pixels(&img);
+    assert_eq!(pixels.len(), 40);
+    assert!(pixels.contains(&(10, 7)) && pixels.contains(&(10, 13)));
+    // the ends are single pixels, the next rows run off the image
+    for (x, y) in pixels {
+        match y {
+            7 | 13 => assert_eq!(x, 10),
+            8 | 12 => assert_ne!(x, 10),
+            _ => panic!("({x}, {y})"),
+        }
+    }
+    let mut img = canvas(20, 20);
+    img.fill_ellipse((10, 10), 3, u32::MAX, Paint::White);
+    // only the center row reaches the full width of 7
+    assert_eq!(white_pixels(&img).len(), 7 + 19 * 5);
+    assert!(img.get(7, 10) && img.get(13, 10) && !img.get(7, 9) && img.get(8, 0));
+}
+
+#[test]
+fn invert_paint() {
+    let mut img = canvas(30, 30);
+    img.fill_rect((0, 0), 15, 30, Paint::White);
+    let original = img.clone();
+
+    img.draw_polyline(&[(0, 0), (29, 29), (0, 29), (29, 0)], Paint::Invert);
+    img.draw_circle((15, 15), 10, Paint::Invert);
+    assert_ne!(img.pixels, original.pixels);
+    assert!(!img.get(0, 0) && img.get(29, 29));
+
+    img.draw_circle((15, 15), 10, Paint::Invert);
+    img.draw_polyline(&[(0, 0), (29, 29), (0, 29), (29, 0)], Paint::Invert);
+    assert_eq!(img.pixels, original.pixels);
+
+    // every shape inverts the pixels it covers once
+    let shapes: [fn(&mut BWImage, Paint); 9] = [
+        |img, paint| img.draw_pixel((4, 7), paint),
+        |img, paint| img.draw_line((-3, 2), (27, 25), paint),
+        |img, paint| img.fill_rect((5, 5), 12, 40, paint),
+        |img, paint| img.draw_rect((2, 3), 20, 9, paint),
+        |img, paint| img.fill_circle((20, 12), 9, paint),
+        |img, paint| img.draw_ellipse((12, 14), 10, 4, paint),
+        |img, paint| img.fill_polygon(&[(3, 25), (28, 4), (28, 28)], paint),
+        |img, paint| img.draw_polyline(&[(20, 5), (5, 5), (25, 25), (5, 25), (20, 5)], paint),
+        |img, paint| img.draw_polygon(&[(-4, 10), (12, 2), (40, 22), (8, 20)], paint),
+    ];
+    for shape in shapes {
+        let mut mask = canvas(30, 30);
+        shape(&mut mask, Paint::White);
+        let mut inverted = original.clone();
+        shape(&mut inverted, Paint::Invert);
+        assert_eq!(
+            inverted.pixels,
+            original.compose(&mask, 0, 0, RasterOp::Xor).pixels
+        );
+    }
+}
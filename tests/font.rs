@@ -0,0 +1,213 @@
+use bw_img::{
+    draw::Paint,
+    font::{BitmapFont, TextAlign, TextStyle},
+    img::BWImageSize,
+    BWImage,
+};
+
+fn canvas(width: u32, height: u32) -> BWImage {
+    BWImage::new(BWImageSize { width, height }, false)
+}
+
+fn white_count(img: &BWImage) -> usize {
+    (0..img.size.height)
+        .flat_map(|y| (0..img.size.width).map(move |x| (x, y)))
+        .filter(|(x, y)| img.get(*x, *y))
+        .count()
+}
+
+#[test]
+fn builtin_fonts() {
+    for font in [BitmapFont::font_5x7(), BitmapFont::font_8x13()] {
+        let advance = font.glyph('A').unwrap().advance;
+        assert_eq!(font.text_width("Hello"), 5 * advance);
+        // missing characters use the fallback glyph
+        assert_eq!(font.text_width("\u{e9}"), advance);
+
+        let style = TextStyle::new(font, Paint::White);
+        let size = style.measure("ab\nc");
+        assert_eq!(size.width, 2 * advance);
+        assert_eq!(size.height, 2 * font.line_height());
+
+        let mut img = canvas(40, 30);
+        assert_eq!(img.draw_text("ab\nc", (1, 1), &style), size);
+        assert!(white_count(&img) > 0);
+        // nothing is drawn outside of the text area
+        let inside = img.crop(1, 1, size.width, size.height);
+        assert_eq!(white_count(&inside), white_count(&img));
+
+        // a space has no ink
+        let mut img = canvas(20, 20);
+        img.draw_text("  ", (0, 0), &style);
+        assert_eq!(white_count(&img), 0);
+    }
+}
+
+#[test]
+fn paint_modes() {
+    let font = BitmapFont::font_5x7();
+    let mut img = canvas(10, 10);
+    img.draw_text("X", (0, 0), &TextStyle::new(font, Paint::White));
+    let ink = white_count(&img);
+
+    let mut black = BWImage::new(img.size, true);
+    black.draw_text("X", (0, 0), &TextStyle::new(font, Paint::Black));
+    assert_eq!(white_count(&black), 100 - ink);
+
+    img.draw_text("X", (0, 0), &TextStyle::new(font, Paint::Invert));
+    assert_eq!(white_count(&img), 0);
+}
+
+const BDF: &str = "STARTFONT 2.1
+FONT -test-fixed
+SIZE 4 75 75
+FONTBOUNDINGBOX 4 5 0 -1
+STARTPROPERTIES 2
+FONT_ASCENT 4
+FONT_DESCENT 1
+ENDPROPERTIES
+CHARS 2
+STARTCHAR A
+ENCODING 65
+SWIDTH 500 0
+DWIDTH 4 0
+BBX 3 4 0 0
+BITMAP
+40
+A0
+E0
+A0
+ENDCHAR
+STARTCHAR period
+ENCODING 46
+SWIDTH 500 0
+DWIDTH 2 0
+BBX 1 1 0 -1
+BITMAP
+80
+ENDCHAR
+ENDFONT
+";
+
+#[test]
+fn parse_bdf() {
+    let font = BitmapFont::parse_bdf(BDF).unwrap();
+    assert_eq!((font.ascent, font.descent), (4, 1));
+    assert_eq!(font.text_width("A.A"), 10);
+
+    let a = font.glyph('A').unwrap();
+    assert_eq!(a.offset, (0, 0));
+    assert_eq!(
+        a.bitmap.size,
+        BWImageSize {
+            width: 3,
+            height: 4
+        }
+    );
+    assert!(a.bitmap.get(1, 0) && !a.bitmap.get(0, 0) && a.bitmap.get(2, 3));
+
+    // the period is below the baseline
+    assert_eq!(font.glyph('.').unwrap().offset, (0, 4));
+    let mut img = canvas(8, 5);
+    img.draw_text(".", (0, 0), &TextStyle::new(&font, Paint::White));
+    assert!(img.get(0, 4));
+    assert_eq!(white_count(&img), 1);
+
+    assert!(BitmapFont::parse_bdf("STARTCHAR A\nENCODING 65\nENDCHAR\n").is_err());
+    // the bitmap must cover the BBX, huge boxes are rejected before allocating
+    for glyph in [
+        "BBX 100000 100000 0 0\nBITMAP\n",
+        "BBX 2147483647 2147483647 0 0\nBITMAP\n",
+        "BBX 3 4 0 0\nBITMAP\n40\nA0\nE0\n",
+        "BBX 9 2 0 0\nBITMAP\n40\nA0\n",
+    ] {
+        let bdf = format!("FONT_ASCENT 4\nSTARTCHAR A\nENCODING 65\n{glyph}ENDCHAR\n");
+        assert!(BitmapFont::parse_bdf(&bdf).is_err());
+    }
+    // offsets out of the i32 range
+    for bdf in [
+        "FONT_ASCENT 2147483647\nSTARTCHAR A\nENCODING 65\nBBX 1 1 0 -2147483648\nBITMAP\n80\nENDCHAR\n",
+        "FONTBOUNDINGBOX 1 2147483647 0 1\n",
+        "FONTBOUNDINGBOX 1 1 0 -2147483648\n",
+    ] {
+        assert!(BitmapFont::parse_bdf(bdf).is_err());
+    }
+}
+
+#[test]
+fn parse_psf() {
+    // psf2 font with 2 glyphs of 3x2 pixels and a unicode table
+    let mut data = vec![0x72, 0xb5, 0x4a, 0x86];
+    for v in [0u32, 32, 1, 2, 2, 2, 3] {
+        data.extend(v.to_le_bytes());
+    }
+    data.extend([0b1010_0000, 0b0100_0000, 0b1110_0000, 0b1110_0000]);
+    data.extend("x\u{d7}".as_bytes());
+    data.push(0xff);
+    data.extend(b"#\xff");
+
+    let font = BitmapFont::parse_psf(&data).unwrap();
+    assert_eq!((font.ascent, font.descent), (2, 0));
+    let x = font.glyph('\u{d7}').unwrap();
+    assert_eq!(
+        x.bitmap.size,
+        BWImageSize {
+            width: 3,
+            height: 2
+        }
+    );
+    assert!(x.bitmap.get(0, 0) && !x.bitmap.get(1, 0) && x.bitmap.get(1, 1));
+    assert_eq!(white_count(&font.glyph('#').unwrap().bitmap), 6);
+    assert!(font.glyph('a').is_none());
+
+    // psf1 without a unicode table
+    let mut data = vec![0x36, 0x04, 0x00, 0x01];
+    data.extend((0..=255u8).map(|i| i & 0xf0));
+    let font = BitmapFont::parse_psf(&data).unwrap();
+    assert_eq!(font.glyph('A').unwrap().bitmap.pixels, vec![0x40]);
+
+    assert!(BitmapFont::parse_psf(&[0, 1, 2, 3]).is_err());
+    assert!(BitmapFont::parse_psf(&data[..100]).is_err());
+
+    // crafted psf2 headers claiming a huge number of empty or missing glyphs
+    for (count, charsize, height, width) in [(u32::MAX, 0, 0, 0), (u32::MAX, 1, 1, 8)] {
+        let mut data = vec![0x72, 0xb5, 0x4a, 0x86];
+        for v in [0u32, 32, 1, count, charsize, height, width] {
+            data.extend(v.to_le_bytes());
+        }
+        assert!(BitmapFont::parse_psf(&data).is_err());
+    }
+}
+
+#[test]
+fn wrapping_and_alignment() {
+    let font = BitmapFont::font_5x7();
+    assert_eq!(font.wrap("aa bb cc", 25), vec!["aa bb", "cc"]);
+    assert_eq!(font.wrap("abcdefg", 15), vec!["abc", "def", "g"]);
+    assert_eq!(font.wrap("a\n\nb", 100), vec!["a", "", "b"]);
+
+    let mut style = TextStyle::new(font, Paint::White);
+    style.max_width = Some(25);
+    style.line_spacing = 2;
+    assert_eq!(
+        style.measure("aa bb cc"),
+        BWImageSize {
+            width: 25,
+            height: 2 * 7 + 2
+        }
+    );
+
+    style.align = TextAlign::Center;
+    let mut img = canvas(25, 16);
+    img.draw_text("aa bb cc", (0, 0), &style);
+    // "cc" is centered on the second line
+    let second = img.crop(0, 9, 25, 7);
+    assert_eq!(white_count(&second.crop(0, 0, 7, 7)), 0);
+    assert_eq!(white_count(&second.crop(18, 0, 7, 7)), 0);
+    assert!(white_count(&second) > 0);
+
+    style.align = TextAlign::Right;
+    let mut img = canvas(25, 16);
+    img.draw_text("aa bb cc", (0, 0), &style);
+    assert_eq!(white_count(&img.crop(0, 9, 15, 7)), 0);
+}
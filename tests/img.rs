@@ -1,4 +1,7 @@
-use bw_img::{iter_direction, BWByteData, BWImage, IterOutput, NormalImage};
+use bw_img::{
+    img::BWImageSize, iter_direction, threshold::Adaptive, AlphaMode, BWByteData, BWDataErr,
+    BWImage, ImageData, IterOutput, LumaModel, NormalImage, PixelFormat, RawData, RgbData,
+};
 
 static RUST_BW: &[u8] = include_bytes!("../assets/rust.txt");
 static RUST: &[u8] = include_bytes!("../assets/rust.png");
@@ -45,6 +48,7 @@ fn img_rs_vertical() {
 
     let mut rotated = String::new();
     let rows = out[0].len();
+    #[allow(clippy::needless_range_loop)]
     for col in 0..img.size.width as usize {
         (0..rows).for_each(|row| {
             rotated.push_str(if out[row][col] { "██" } else { "  " });
@@ -54,3 +58,255 @@ fn img_rs_vertical() {
 
     assert_eq!(RUST_BW, rotated.as_bytes());
 }
+
+#[test]
+fn luma_models() {
+    assert_eq!(LumaModel::Bt601.luma(200, 30, 30), 80);
+    assert_eq!(LumaModel::Bt709.luma(0, 255, 0), 182);
+    assert_eq!(LumaModel::Average.luma(255, 255, 0), 170);
+    assert_eq!(LumaModel::Max.luma(10, 20, 30), 30);
+    assert_eq!(LumaModel::Min.luma(10, 20, 30), 10);
+    assert_eq!(LumaModel::Linear.luma(128, 128, 128), 128);
+    assert_eq!(LumaModel::Linear.luma(0, 0, 255), 76);
+
+    // red ink on white paper, only the green channel tells them apart
+    let data = [[255u8, 255, 255], [200, 30, 30]].repeat(4).concat();
+    let mut rgb = RgbData::new(&data, 8, 1);
+    rgb.set_luma_model(LumaModel::Red);
+    assert_eq!(rgb.parse_bw_image().unwrap().pixels, vec![0xff]);
+    rgb.set_luma_model(LumaModel::Green);
+    assert_eq!(rgb.parse_bw_image().unwrap().pixels, vec![0b1010_1010]);
+}
+
+#[test]
+fn alpha_compositing() {
+    // opaque black, transparent black, half transparent black, opaque white
+    let data = [
+        [0u8, 0, 0, 255],
+        [0, 0, 0, 0],
+        [0, 0, 0, 100],
+        [255, 255, 255, 255],
+    ]
+    .repeat(2)
+    .concat();
+    let mut rgba = RawData::new(&data, 8, 1, PixelFormat::Rgba32);
+    assert_eq!(rgba.parse_bw_image().unwrap().pixels, vec![0b0001_0001]);
+
+    rgba.set_alpha_mode(AlphaMode::Composite([255, 255, 255]));
+    assert_eq!(rgba.parse_bw_image().unwrap().pixels, vec![0b0111_0111]);
+
+    rgba.set_alpha_mode(AlphaMode::Cutoff {
+        cutoff: 128,
+        color: [255, 255, 255],
+    });
+    assert_eq!(rgba.parse_bw_image().unwrap().pixels, vec![0b0111_0111]);
+    rgba.set_alpha_mode(AlphaMode::Cutoff {
+        cutoff: 50,
+        color: [255, 255, 255],
+    });
+    assert_eq!(rgba.parse_bw_image().unwrap().pixels, vec![0b0101_0101]);
+
+    let rust = image::load_from_memory(RUST).unwrap();
+    let mut normal = NormalImage::new(&rust);
+    let ignored = normal.parse_bw_image().unwrap();
+    normal.set_alpha_mode(AlphaMode::Composite([255, 255, 255]));
+    let composited = normal.parse_bw_image().unwrap();
+    let rgba = rust.to_rgba8();
+    let raw = RawData::new(rgba.as_raw(), 64, 64, PixelFormat::Rgba32);
+    assert_eq!(ignored.pixels, raw.parse_bw_image().unwrap().pixels);
+    assert!(composited
+        .pixels
+        .iter()
+        .zip(&ignored.pixels)
+        .all(|(c, i)| c | i == *c));
+    assert_ne!(composited.pixels, ignored.pixels);
+}
+
+#[test]
+fn raw_pixel_formats() {
+    let pixels = [[0u8, 0, 0], [255, 255, 255], [200, 30, 30], [30, 30, 200]].repeat(2);
+    let rgb = pixels.concat();
+    let expected = |model: LumaModel| {
+        let mut rgb_data = RgbData::new(&rgb, 8, 1);
+        rgb_data.set_luma_model(model);
+        rgb_data.parse_bw_image().unwrap().pixels
+    };
+    let encode = |format: PixelFormat| -> Vec<u8> {
+        pixels
+            .iter()
+            .flat_map(|&[r, g, b]| match format {
+                PixelFormat::Rgb24 => vec![r, g, b],
+                PixelFormat::Rgba32 => vec![r, g, b, 255],
+                PixelFormat::Bgr24 => vec![b, g, r],
+                PixelFormat::Bgra32 => vec![b, g, r, 255],
+                PixelFormat::Rgb565 => {
+                    let v = ((r as u16 >> 3) << 11) | ((g as u16 >> 2) << 5) | (b as u16 >> 3);
+                    v.to_le_bytes().to_vec()
+                }
+                _ => unreachable!(),
+            })
+            .collect()
+    };
+
+    for format in [
+        PixelFormat::Rgb24,
+        PixelFormat::Rgba32,
+        PixelFormat::Bgr24,
+        PixelFormat::Bgra32,
+        PixelFormat::Rgb565,
+    ] {
+        for model in [LumaModel::Bt601, LumaModel::Red, LumaModel::Blue] {
+            let data = encode(format);
+            let mut raw = RawData::new(&data, 8, 1, format);
+            raw.set_luma_model(model);
+            assert_eq!(
+                raw.parse_bw_image().unwrap().pixels,
+                expected(model),
+                "{format:?} {model:?}"
+            );
+        }
+    }
+
+    let gray = [10u8, 200, 127, 129, 0, 255, 128, 130];
+    let gray16 = gray.map(|v| v as u16 * 257);
+    let le = gray16
+        .iter()
+        .flat_map(|v| v.to_le_bytes())
+        .collect::<Vec<_>>();
+    let be = gray16
+        .iter()
+        .flat_map(|v| v.to_be_bytes())
+        .collect::<Vec<_>>();
+    for (data, format) in [
+        (&gray[..], PixelFormat::Gray8),
+        (&le, PixelFormat::Gray16Le),
+        (&be, PixelFormat::Gray16Be),
+    ] {
+        let raw = RawData::new(data, 8, 1, format);
+        assert_eq!(raw.to_gray_data().unwrap(), gray);
+        assert_eq!(raw.parse_bw_image().unwrap().pixels, vec![0b0101_0101]);
+    }
+}
+
+#[test]
+fn row_stride() {
+    // 8x2 image, every row padded to 32 bytes with garbage
+    let row = [[255u8, 255, 255], [0, 0, 0]].repeat(4).concat();
+    let mut data = vec![];
+    for _ in 0..2 {
+        data.extend(&row);
+        data.extend([0xaa; 8]);
+    }
+
+    let padded = RgbData::with_stride(&data, 8, 2, 32)
+        .parse_bw_image()
+        .unwrap();
+    let packed = RgbData::new(&row.repeat(2), 8, 2).parse_bw_image().unwrap();
+    assert_eq!(padded.pixels, vec![0b1010_1010; 2]);
+    assert_eq!(padded.pixels, packed.pixels);
+
+    let raw = RawData::with_stride(&data, 8, 2, PixelFormat::Rgb24, 32);
+    assert_eq!(raw.parse_bw_image().unwrap().pixels, packed.pixels);
+    // the padding of the last row may be missing
+    let raw = RawData::with_stride(&data[..56], 8, 2, PixelFormat::Rgb24, 32);
+    assert_eq!(raw.parse_bw_image().unwrap().pixels, packed.pixels);
+}
+
+#[test]
+fn rgb_data_odd_width() {
+    let img = image::load_from_memory(RUST)
+        .unwrap()
+        .crop_imm(0, 0, 61, 64);
+    let rgb = img.to_rgb8();
+    let from_rgb = RgbData::new(rgb.as_raw(), 61, 64).parse_bw_image().unwrap();
+    let from_normal = NormalImage::new(&img).parse_bw_image().unwrap();
+
+    assert_eq!(
+        from_rgb.pixels.len() as u64,
+        from_rgb.size.get_padded_bytes_len()
+    );
+    assert_eq!(from_rgb.size, from_normal.size);
+    assert_eq!(from_rgb.pixels, from_normal.pixels);
+}
+
+#[test]
+fn short_buffer() {
+    let data = vec![255u8; 8 * 3 * 2];
+    assert!(matches!(
+        RgbData::new(&data[..47], 8, 2).parse_bw_image(),
+        Err(BWDataErr::WrongSize(8, 2, 47))
+    ));
+    assert!(matches!(
+        RgbData::with_stride(&data, 8, 2, 16).parse_bw_image(),
+        Err(BWDataErr::WrongStride(16, 24))
+    ));
+    // a single row still needs a whole row of data
+    assert!(matches!(
+        RgbData::with_stride(&[255; 30], 10, 1, 5).parse_bw_image(),
+        Err(BWDataErr::WrongStride(5, 30))
+    ));
+    // a stride too large for the buffer length to be computed
+    assert!(matches!(
+        RgbData::with_stride(&data, 8, 3, usize::MAX / 2).parse_bw_image(),
+        Err(BWDataErr::WrongSize(8, 3, 48))
+    ));
+    assert!(matches!(
+        RawData::with_stride(&data, 8, 1, PixelFormat::Rgb24, 0).parse_bw_image(),
+        Err(BWDataErr::WrongStride(0, 24))
+    ));
+    assert!(matches!(
+        RawData::new(&data, 8, 2, PixelFormat::Rgba32).parse_bw_image(),
+        Err(BWDataErr::WrongSize(8, 2, 48))
+    ));
+    assert!(RawData::new(&data, 4, 2, PixelFormat::Rgb565)
+        .binarize_with(Adaptive::sauvola(3))
+        .parse_bw_image()
+        .is_ok());
+    assert!(matches!(
+        RgbData::new(&data, 8, 3)
+            .binarize_with(Adaptive::sauvola(3))
+            .parse_bw_image(),
+        Err(BWDataErr::WrongSize(8, 3, 48))
+    ));
+}
+
+#[test]
+fn pixel_access() {
+    let size = BWImageSize {
+        width: 13,
+        height: 3,
+    };
+    let mut img = BWImage::new(size, true);
+    assert_eq!(size.get_padded_row_len(), 2);
+    assert_eq!(img.pixels, [0xff, 0b1111_1000].repeat(3));
+
+    img.set(0, 0, false);
+    img.set(12, 2, false);
+    img.set(9, 1, false);
+    assert!(!img.get(0, 0) && !img.get(12, 2) && !img.get(9, 1));
+    assert!(img.get(1, 0) && img.get(12, 1));
+    assert_eq!(
+        img.pixels,
+        vec![0x7f, 0b1111_1000, 0xff, 0b1011_1000, 0xff, 0b1111_0000]
+    );
+
+    assert_eq!(img.get_checked(13, 0), None);
+    assert_eq!(img.get_checked(0, 3), None);
+    assert_eq!(img.set_checked(13, 0, true), None);
+    assert_eq!(img.set_checked(12, 0, false), Some(()));
+    assert_eq!(img.get_checked(12, 0), Some(false));
+
+    img.fill(false);
+    assert_eq!(img.pixels, vec![0; 6]);
+
+    let rust = BWImage::parse(&NormalImage::new(&image::load_from_memory(RUST).unwrap())).unwrap();
+    let lines = std::str::from_utf8(RUST_BW)
+        .unwrap()
+        .lines()
+        .collect::<Vec<_>>();
+    for (y, line) in lines.iter().enumerate() {
+        for (x, c) in line.chars().step_by(2).enumerate() {
+            assert_eq!(rust.get(x as u32, y as u32), c == '█');
+        }
+    }
+}
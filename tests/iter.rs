@@ -0,0 +1,494 @@
+use bw_img::{
+    img::BWImageSize,
+    iter_direction::{
+        BottomUp, BottomUpRev, Horizontal, HorizontalRev, Pages, Rotate180, Vertical, VerticalRev,
+    },
+    BWImage, BWIterState, IterOutput, PixelDirection, SeekDirection,
+};
+
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, max: u32) -> u32 {
+        (self.next() % max as u64) as u32
+    }
+}
+
+fn random_image(rng: &mut Rng, width: u32, height: u32) -> BWImage {
+    let mut img = BWImage::new(BWImageSize { width, height }, false);
+    for y in 0..height {
+        for x in 0..width {
+            img.set(x, y, rng.next() & 1 == 1);
+        }
+    }
+    img
+}
+
+/// Rebuild an image from the bytes of lines, `pixel` maps the line, the position in the line
+/// and the bit index in the byte to the position of the pixel and its bit in the byte
+fn rebuild(
+    size: BWImageSize,
+    iter: impl Iterator<Item = IterOutput>,
+    lines: u32,
+    line_len: u32,
+    pixel: impl Fn(u32, u32) -> (u32, u32),
+    bit: impl Fn(usize) -> u8,
+) -> BWImage {
+    let mut img = BWImage::new(size, false);
+    let (mut line, mut pos) = (0, 0);
+    for out in iter {
+        match out {
+            IterOutput::Byte { byte, len } => {
+                assert!(line < lines, "byte after the last line");
+                assert_eq!(len as u32, 8.min(line_len - pos), "length at {line}, {pos}");
+                for i in 0..len {
+                    let (x, y) = pixel(line, pos + i as u32);
+                    img.set(x, y, byte & bit(i) != 0);
+                }
+                let unused = (0..8).filter(|i| *i >= len).fold(0, |m, i| m | bit(i));
+                assert_eq!(byte & unused, 0, "bits after the length are set");
+                pos += len as u32;
+            }
+            IterOutput::NewLine => {
+                assert_eq!(pos, line_len, "line {line} ended early");
+                line += 1;
+                pos = 0;
+            }
+        }
+    }
+    assert_eq!(line, lines);
+    img
+}
+
+fn check_directions(img: &BWImage) {
+    let size = img.size;
+    let (w, h) = (size.width, size.height);
+
+    let horizontal = rebuild(
+        size,
+        img.iterator(Horizontal),
+        h,
+        w,
+        |l, p| (p, l),
+        |i| 0x80 >> i,
+    );
+    assert_eq!(&horizontal, img, "Horizontal {w}x{h}");
+
+    let horizontal_rev = rebuild(
+        size,
+        img.iterator(HorizontalRev),
+        h,
+        w,
+        |l, p| (p, l),
+        |i| 1 << i,
+    );
+    assert_eq!(&horizontal_rev, img, "HorizontalRev {w}x{h}");
+
+    let bottom_up = rebuild(
+        size,
+        img.iterator(BottomUp),
+        h,
+        w,
+        |l, p| (p, h - 1 - l),
+        |i| 0x80 >> i,
+    );
+    assert_eq!(&bottom_up, img, "BottomUp {w}x{h}");
+
+    let bottom_up_rev = rebuild(
+        size,
+        img.iterator(BottomUpRev),
+        h,
+        w,
+        |l, p| (p, h - 1 - l),
+        |i| 1 << i,
+    );
+    assert_eq!(&bottom_up_rev, img, "BottomUpRev {w}x{h}");
+
+    let rotated = rebuild(
+        size,
+        img.iterator(Rotate180),
+        h,
+        w,
+        |l, p| (w - 1 - p, h - 1 - l),
+        |i| 0x80 >> i,
+    );
+    assert_eq!(&rotated, img, "Rotate180 {w}x{h}");
+    assert!(img
+        .iterator(Rotate180)
+        .eq(img.rotate_180().iterator(Horizontal)));
+
+    let vertical = rebuild(
+        size,
+        img.iterator(Vertical),
+        w,
+        h,
+        |l, p| (l, p),
+        |i| 0x80 >> i,
+    );
+    assert_eq!(&vertical, img, "Vertical {w}x{h}");
+
+    let vertical_rev = rebuild(
+        size,
+        img.iterator(VerticalRev),
+        w,
+        h,
+        |l, p| (l, p),
+        |i| 1 << i,
+    );
+    assert_eq!(&vertical_rev, img, "VerticalRev {w}x{h}");
+}
+
+#[test]
+fn directions_reconstruct_random_images() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    for (w, h) in [(1, 1), (8, 8), (61, 3), (3, 61), (64, 17)] {
+        let img = random_image(&mut rng, w, h);
+        check_directions(&img);
+    }
+    for _ in 0..50 {
+        let (w, h) = (rng.below(70) + 1, rng.below(40) + 1);
+        let img = random_image(&mut rng, w, h);
+        check_directions(&img);
+    }
+}
+
+#[test]
+fn views_reconstruct_random_areas() {
+    let mut rng = Rng(42);
+    for _ in 0..30 {
+        let (w, h) = (rng.below(70) + 1, rng.below(40) + 1);
+        let img = random_image(&mut rng, w, h);
+        let (x, y) = (rng.below(w), rng.below(h));
+        let view = img.view(x, y, rng.below(w) + 1, rng.below(h) + 1);
+        let area = view.to_image();
+        let size = area.size;
+
+        let horizontal = rebuild(
+            size,
+            view.iterator(Horizontal),
+            size.height,
+            size.width,
+            |l, p| (p, l),
+            |i| 0x80 >> i,
+        );
+        assert_eq!(horizontal, area);
+        let vertical = rebuild(
+            size,
+            view.iterator(Vertical),
+            size.width,
+            size.height,
+            |l, p| (l, p),
+            |i| 0x80 >> i,
+        );
+        assert_eq!(vertical, area);
+        for (ax, ay) in [(0, 0), (size.width - 1, size.height - 1)] {
+            assert_eq!(area.get(ax, ay), img.get(x + ax, y + ay));
+        }
+    }
+}
+
+#[test]
+fn reversed_directions_at_edges() {
+    // the bottom row is 1100000001
+    let mut img = BWImage::new(
+        BWImageSize {
+            width: 10,
+            height: 2,
+        },
+        false,
+    );
+    for x in [0, 1, 9] {
+        img.set(x, 1, true);
+    }
+    let bytes = |iter: &mut dyn Iterator<Item = IterOutput>| {
+        iter.filter_map(|out| match out {
+            IterOutput::Byte { byte, len } => Some((byte, len)),
+            IterOutput::NewLine => None,
+        })
+        .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        bytes(&mut img.iterator(HorizontalRev)),
+        vec![(0, 8), (0, 2), (0b11, 8), (0b10, 2)]
+    );
+    assert_eq!(
+        bytes(&mut img.iterator(BottomUp)),
+        vec![(0b1100_0000, 8), (0b0100_0000, 2), (0, 8), (0, 2)]
+    );
+    assert_eq!(
+        bytes(&mut img.iterator(BottomUpRev)),
+        vec![(0b11, 8), (0b10, 2), (0, 8), (0, 2)]
+    );
+    assert_eq!(
+        bytes(&mut img.iterator(Rotate180)),
+        vec![(0b1000_0000, 8), (0b1100_0000, 2), (0, 8), (0, 2)]
+    );
+}
+
+#[test]
+fn pages() {
+    let mut rng = Rng(7);
+    for (w, h) in [(1, 1), (128, 64), (13, 8), (5, 13), (20, 17)] {
+        let img = random_image(&mut rng, w, h);
+        let pages = h.div_ceil(8);
+        let buf = img.to_page_buffer();
+        assert_eq!(buf.len(), (w * pages) as usize);
+        for (i, byte) in buf.iter().enumerate() {
+            let (x, page) = (i as u32 % w, i as u32 / w);
+            for bit in 0..8 {
+                let y = page * 8 + bit;
+                let expected = y < h && img.get(x, y);
+                assert_eq!(byte & (1 << bit) != 0, expected, "{w}x{h} at ({x}, {y})");
+            }
+        }
+
+        // every page ends with a new line, the last page may be shorter
+        let mut iter = img.iterator(Pages);
+        let lens: Vec<_> = iter
+            .by_ref()
+            .take(w as usize)
+            .map(|out| match out {
+                IterOutput::Byte { len, .. } => len,
+                IterOutput::NewLine => panic!("early new line"),
+            })
+            .collect();
+        assert_eq!(lens, vec![8.min(h as usize); w as usize]);
+        assert_eq!(iter.next(), Some(IterOutput::NewLine));
+        assert_eq!(img.iterator(Pages).count(), (pages * (w + 1)) as usize);
+        if h % 8 != 0 {
+            let last = img.iterator(Pages).nth(((pages - 1) * (w + 1)) as usize);
+            assert!(matches!(last, Some(IterOutput::Byte { len, .. }) if len == h as usize % 8));
+        }
+    }
+}
+
+fn check_pixels<D: PixelDirection>(img: &BWImage, direction: D) -> Vec<(u32, u32, bool)> {
+    let pixels: Vec<_> = img.pixel_iter(direction).collect();
+    assert_eq!(pixels.len(), (img.size.width * img.size.height) as usize);
+    let mut seen = BWImage::new(img.size, false);
+    for (x, y, white) in &pixels {
+        assert!(!seen.get(*x, *y), "({x}, {y}) returned twice");
+        seen.set(*x, *y, true);
+        assert_eq!(img.get(*x, *y), *white);
+    }
+    pixels
+}
+
+fn check_runs<D: PixelDirection>(img: &BWImage, direction: D) -> Vec<(u32, u32, u32, bool)> {
+    let runs: Vec<_> = img.run_iter(direction).collect();
+    let mut covered = BWImage::new(img.size, false);
+    for (y, x, len, white) in &runs {
+        assert!(*len > 0);
+        for x in *x..x + len {
+            assert!(!covered.get(x, *y));
+            covered.set(x, *y, true);
+            assert_eq!(img.get(x, *y), *white);
+        }
+    }
+    assert_eq!(covered, BWImage::new(img.size, true));
+    runs
+}
+
+#[test]
+fn pixel_iterators() {
+    let mut rng = Rng(99);
+    for _ in 0..20 {
+        let (w, h) = (rng.below(40) + 1, rng.below(20) + 1);
+        let img = random_image(&mut rng, w, h);
+
+        let pixels = check_pixels(&img, Horizontal);
+        let row_major: Vec<_> = (0..h)
+            .flat_map(|y| (0..w).map(move |x| (x, y)))
+            .map(|(x, y)| (x, y, img.get(x, y)))
+            .collect();
+        assert_eq!(pixels, row_major);
+        check_pixels(&img, HorizontalRev);
+        check_pixels(&img, BottomUp);
+        check_pixels(&img, BottomUpRev);
+        let rotated = check_pixels(&img, Rotate180);
+        assert_eq!(rotated.first().map(|p| (p.0, p.1)), Some((w - 1, h - 1)));
+        let column_major: Vec<_> = (0..w)
+            .flat_map(|x| (0..h).map(move |y| (x, y)))
+            .map(|(x, y)| (x, y, img.get(x, y)))
+            .collect();
+        assert_eq!(check_pixels(&img, Vertical), column_major);
+        check_pixels(&img, VerticalRev);
+        check_pixels(&img, Pages);
+
+        // the runs alternate colors in a row
+        let runs = check_runs(&img, Horizontal);
+        for pair in runs.windows(2) {
+            if pair[0].0 == pair[1].0 {
+                assert_ne!(pair[0].3, pair[1].3);
+                assert_eq!(pair[0].1 + pair[0].2, pair[1].1);
+            }
+        }
+        assert_eq!(check_runs(&img, Rotate180).len(), runs.len());
+        assert_eq!(check_runs(&img, BottomUpRev).len(), runs.len());
+        check_runs(&img, Vertical);
+    }
+}
+
+#[test]
+fn runs_of_rows() {
+    let mut img = BWImage::new(
+        BWImageSize {
+            width: 20,
+            height: 2,
+        },
+        false,
+    );
+    for x in 3..17 {
+        img.set(x, 0, true);
+    }
+    assert_eq!(
+        img.run_iter(Horizontal).collect::<Vec<_>>(),
+        vec![
+            (0, 0, 3, false),
+            (0, 3, 14, true),
+            (0, 17, 3, false),
+            (1, 0, 20, false)
+        ]
+    );
+    assert_eq!(
+        img.run_iter(Rotate180).collect::<Vec<_>>(),
+        vec![
+            (1, 0, 20, false),
+            (0, 17, 3, false),
+            (0, 3, 14, true),
+            (0, 0, 3, false)
+        ]
+    );
+    // runs of a view are in the view
+    let view = img.view(2, 0, 4, 1);
+    assert_eq!(
+        view.iterator(Horizontal)
+            .pixels()
+            .runs()
+            .collect::<Vec<_>>(),
+        vec![(0, 0, 1, false), (0, 1, 3, true)]
+    );
+}
+
+fn check_seek_and_reverse<D: SeekDirection + PixelDirection + Copy>(img: &BWImage, direction: D) {
+    let full: Vec<_> = img.iterator(direction).collect();
+    let mut iter = img.iterator(direction).seekable();
+    assert_eq!(iter.len(), full.len());
+    iter.next();
+    assert_eq!(iter.size_hint(), (full.len() - 1, Some(full.len() - 1)));
+
+    let mut reversed: Vec<_> = img.iterator(direction).seekable().rev().collect();
+    reversed.reverse();
+    assert_eq!(reversed, full);
+
+    // both ends meet in the middle
+    let mut iter = img.iterator(direction).seekable();
+    let (mut front, mut back) = (vec![], vec![]);
+    while let Some(out) = iter.next() {
+        front.push(out);
+        if let Some(out) = iter.next_back() {
+            back.push(out);
+        }
+        assert_eq!(iter.len(), full.len() - front.len() - back.len());
+    }
+    back.reverse();
+    front.extend(back);
+    assert_eq!(front, full);
+
+    let (w, h) = (img.size.width, img.size.height);
+    for (x, y) in [(0, 0), (w - 1, h - 1), (w / 2, h / 3), (w - 1, 0)] {
+        let seeked: Vec<_> = img.iterator(direction).starting_at(x, y).collect();
+        assert!(!seeked.is_empty());
+        assert_eq!(seeked[..], full[full.len() - seeked.len()..]);
+        let iter = img.iterator(direction).starting_at(x, y);
+        assert_eq!(iter.len(), seeked.len());
+    }
+    let mut iter = img.iterator(direction).seekable();
+    iter.seek(w, 0);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn seek_and_reverse() {
+    let mut rng = Rng(1234);
+    for _ in 0..20 {
+        let (w, h) = (rng.below(40) + 1, rng.below(30) + 1);
+        let img = random_image(&mut rng, w, h);
+        check_seek_and_reverse(&img, Horizontal);
+        check_seek_and_reverse(&img, HorizontalRev);
+        check_seek_and_reverse(&img, BottomUp);
+        check_seek_and_reverse(&img, BottomUpRev);
+        check_seek_and_reverse(&img, Rotate180);
+        check_seek_and_reverse(&img, Vertical);
+        check_seek_and_reverse(&img, VerticalRev);
+        check_seek_and_reverse(&img, Pages);
+    }
+
+    // resume a partial print of a view, 3 bytes and a new line for every row
+    let img = random_image(&mut rng, 30, 10);
+    let view = img.view(3, 2, 20, 5);
+    let mut iter = view.iterator(Horizontal);
+    let printed: Vec<_> = iter.by_ref().take(10).collect();
+    let rest: Vec<_> = view.iterator(Horizontal).starting_at(16, 2).collect();
+    assert_eq!(rest, iter.collect::<Vec<_>>());
+    assert_eq!(printed.len() + rest.len(), 5 * 4);
+
+    // the byte of the pixel in a row of 3 bytes and a new line
+    let img = random_image(&mut rng, 20, 4);
+    let skipped: Vec<_> = img.iterator(Horizontal).skip(5).collect();
+    let seeked: Vec<_> = img.iterator(Horizontal).starting_at(13, 1).collect();
+    assert_eq!(seeked, skipped);
+    let seeked: Vec<_> = img.iterator(Vertical).starting_at(2, 3).collect();
+    assert_eq!(
+        seeked,
+        img.iterator(Vertical).skip(2 * 2).collect::<Vec<_>>()
+    );
+
+    // seeking continues after the outputs already returned
+    let mut iter = img.iterator(Horizontal);
+    iter.next();
+    assert_eq!(iter.seekable().len(), 4 * 4 - 1);
+}
+
+bw_img::define_direction!(Rows, {
+    fn next(&mut self, state: BWIterState) -> Option<((u32, u32), IterOutput)> {
+        let (x, y) = state.current;
+        if y >= state.size.height {
+            return None;
+        }
+        let (byte, len) = state.row_byte(x, y);
+        let next = if x + 8 >= state.size.width {
+            (0, y + 1)
+        } else {
+            (x + 8, y)
+        };
+        Some((next, IterOutput::Byte { byte, len }))
+    }
+});
+
+// the macro doesn't derive anything for directions defined outside of the crate
+impl Clone for Rows {
+    fn clone(&self) -> Self {
+        Rows
+    }
+}
+
+#[test]
+fn user_direction() {
+    let img = random_image(&mut Rng(99), 21, 5);
+    let rows: Vec<_> = img.iterator(Rows.clone()).collect();
+    assert_eq!(
+        rows,
+        img.iterator(Horizontal)
+            .filter(|out| *out != IterOutput::NewLine)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(img.iterator(Rows).size_hint(), (0, None));
+}
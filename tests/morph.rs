@@ -0,0 +1,107 @@
+use bw_img::{img::BWImageSize, morph::StructuringElement, BWImage};
+
+fn random_image(width: u32, height: u32, seed: u64) -> BWImage {
+    let mut state = seed;
+    let mut img = BWImage::new(BWImageSize { width, height }, false);
+    for y in 0..height {
+        for x in 0..width {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            img.set(x, y, state.is_multiple_of(3));
+        }
+    }
+    img
+}
+
+/// Pixel by pixel erosion or dilation
+fn naive(img: &BWImage, element: &StructuringElement, erode: bool) -> BWImage {
+    let mask = element.mask();
+    let (ox, oy) = element.origin();
+    let mut out = BWImage::new(img.size, false);
+    for y in 0..img.size.height {
+        for x in 0..img.size.width {
+            let mut under = (0..mask.size.height)
+                .flat_map(|j| (0..mask.size.width).map(move |i| (i, j)))
+                .filter(|(i, j)| mask.get(*i, *j))
+                .map(|(i, j)| {
+                    let (dx, dy) = (i as i64 - ox as i64, j as i64 - oy as i64);
+                    let (sx, sy) = if erode {
+                        (x as i64 + dx, y as i64 + dy)
+                    } else {
+                        (x as i64 - dx, y as i64 - dy)
+                    };
+                    if sx < 0 || sy < 0 {
+                        return erode;
+                    }
+                    img.get_checked(sx as u32, sy as u32).unwrap_or(erode)
+                });
+            let white = if erode {
+                under.all(|p| p)
+            } else {
+                under.any(|p| p)
+            };
+            out.set(x, y, white);
+        }
+    }
+    out
+}
+
+#[test]
+fn matches_naive() {
+    let mut custom = BWImage::new(
+        BWImageSize {
+            width: 4,
+            height: 2,
+        },
+        false,
+    );
+    custom.set(0, 0, true);
+    custom.set(3, 0, true);
+    custom.set(1, 1, true);
+    let custom = StructuringElement::new(custom, (1, 0)).unwrap();
+    let elements = [
+        StructuringElement::square(3),
+        StructuringElement::rect(9, 2),
+        StructuringElement::cross(2),
+        custom,
+    ];
+
+    for (i, (width, height)) in [(1, 1), (7, 5), (64, 3), (70, 9), (130, 4)]
+        .into_iter()
+        .enumerate()
+    {
+        let img = random_image(width, height, i as u64 + 1);
+        for element in &elements {
+            assert_eq!(img.erode(element), naive(&img, element, true));
+            assert_eq!(img.dilate(element), naive(&img, element, false));
+        }
+    }
+}
+
+#[test]
+fn open_and_close() {
+    let size = BWImageSize {
+        width: 20,
+        height: 10,
+    };
+    let square = StructuringElement::square(3);
+
+    // opening removes a speckle and keeps a block
+    let mut img = BWImage::new(size, false);
+    img.fill_rect((2, 2), 6, 5, bw_img::draw::Paint::White);
+    let block = img.clone();
+    img.set(15, 5, true);
+    assert_eq!(img.open(&square), block);
+
+    // closing fills a hole
+    img.set(4, 4, false);
+    assert!(img.close(&square).get(4, 4));
+
+    // a white image stays white at the edges
+    let white = BWImage::new(size, true);
+    assert_eq!(white.erode(&StructuringElement::cross(3)), white);
+    assert_eq!(white.dilate(&square), white);
+
+    assert!(StructuringElement::new(BWImage::new(size, true), (20, 0)).is_none());
+}
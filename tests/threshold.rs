@@ -0,0 +1,83 @@
+use bw_img::{
+    threshold::{histogram, otsu_threshold, Adaptive},
+    BWImage, ImageData, NormalImage, RgbData, Threshold,
+};
+
+static RUST: &[u8] = include_bytes!("../assets/rust.png");
+
+#[test]
+fn otsu_bimodal() {
+    let mut hist = [0u64; 256];
+    hist[40] = 100;
+    hist[200] = 300;
+    let threshold = otsu_threshold(&hist);
+    assert!((40..200).contains(&threshold));
+
+    // dark gray and light gray, both on the same side of the default threshold
+    let data = [
+        [60u8; 3], [60; 3], [100; 3], [100; 3], [60; 3], [100; 3], [60; 3], [100; 3],
+    ]
+    .concat();
+    let mut rgb = RgbData::new(&data, 8, 1);
+    assert_eq!(BWImage::parse(&rgb).unwrap().pixels, vec![0]);
+
+    rgb.set_threshold(Threshold::Otsu);
+    let threshold = rgb.resolve_threshold();
+    assert!((60..100).contains(&threshold));
+    assert_eq!(BWImage::parse(&rgb).unwrap().pixels, vec![0b0011_0101]);
+}
+
+#[test]
+fn otsu_normal_image() {
+    let img = image::load_from_memory(RUST).unwrap();
+    let mut normal = NormalImage::new(&img);
+    normal.set_threshold(Threshold::Otsu);
+    let threshold = normal.resolve_threshold();
+
+    let hist = histogram(
+        img.to_rgb8()
+            .pixels()
+            .map(|p| (0.299 * p[0] as f32 + 0.587 * p[1] as f32 + 0.114 * p[2] as f32) as u8),
+    );
+    assert_eq!(threshold, otsu_threshold(&hist));
+
+    normal.set_threshold(Threshold::Fixed(threshold));
+    let fixed = BWImage::parse(&normal).unwrap();
+    normal.set_threshold(Threshold::Otsu);
+    assert_eq!(BWImage::parse(&normal).unwrap().pixels, fixed.pixels);
+}
+
+/// Light gradient with dark strokes, too uneven for a global threshold
+fn gradient_with_strokes() -> (Vec<u8>, Vec<bool>) {
+    let (mut data, mut expected) = (vec![], vec![]);
+    for _ in 0..8 {
+        for x in 0..64u32 {
+            let background = (60 + 3 * x) as u8;
+            let stroke = (6..9).contains(&(x % 16));
+            let value = if stroke { background / 3 } else { background };
+            data.extend([value; 3]);
+            expected.push(!stroke);
+        }
+    }
+    (data, expected)
+}
+
+fn unpack(img: &BWImage) -> Vec<bool> {
+    img.pixels
+        .iter()
+        .flat_map(|byte| (0..8).map(move |i| byte & (1 << (7 - i)) != 0))
+        .collect()
+}
+
+#[test]
+fn adaptive_uneven_lighting() {
+    let (data, expected) = gradient_with_strokes();
+    let rgb = RgbData::new(&data, 64, 8);
+    assert_ne!(unpack(&rgb.parse_bw_image().unwrap()), expected);
+
+    for mode in [Adaptive::bradley(15), Adaptive::sauvola(15)] {
+        let img = rgb.binarize_with(mode).parse_bw_image().unwrap();
+        assert_eq!(img.size.get_padded_bytes_len(), img.pixels.len() as u64);
+        assert_eq!(unpack(&img), expected, "{mode:?}");
+    }
+}
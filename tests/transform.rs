@@ -0,0 +1,184 @@
+use bw_img::{img::BWImageSize, BWImage, NormalImage};
+
+static RUST_BW: &[u8] = include_bytes!("../assets/rust.txt");
+static RUST: &[u8] = include_bytes!("../assets/rust.png");
+
+fn rust() -> BWImage {
+    BWImage::parse(&NormalImage::new(&image::load_from_memory(RUST).unwrap())).unwrap()
+}
+
+fn rust_grid() -> Vec<Vec<char>> {
+    std::str::from_utf8(RUST_BW)
+        .unwrap()
+        .lines()
+        .map(|line| line.chars().step_by(2).collect())
+        .collect()
+}
+
+fn render(img: &BWImage) -> String {
+    let mut out = String::new();
+    for y in 0..img.size.height {
+        for x in 0..img.size.width {
+            out.push_str(if img.get(x, y) { "██" } else { "  " });
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn render_grid(grid: &[Vec<char>]) -> String {
+    let mut out = String::new();
+    for row in grid {
+        for c in row {
+            out.push_str(if *c == '█' { "██" } else { "  " });
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[test]
+fn rust_rotations() {
+    let img = rust();
+    let grid = rust_grid();
+    let n = grid.len();
+    assert_eq!(render(&img).as_bytes(), RUST_BW);
+
+    let expected = |f: &dyn Fn(usize, usize) -> char| {
+        render_grid(
+            &(0..n)
+                .map(|y| (0..n).map(|x| f(x, y)).collect())
+                .collect::<Vec<_>>(),
+        )
+    };
+    assert_eq!(
+        render(&img.rotate_90()),
+        expected(&|x, y| grid[n - 1 - x][y])
+    );
+    assert_eq!(
+        render(&img.rotate_180()),
+        expected(&|x, y| grid[n - 1 - y][n - 1 - x])
+    );
+    assert_eq!(
+        render(&img.rotate_270()),
+        expected(&|x, y| grid[x][n - 1 - y])
+    );
+    assert_eq!(render(&img.transpose()), expected(&|x, y| grid[x][y]));
+    assert_eq!(
+        render(&img.flip_horizontal()),
+        expected(&|x, y| grid[y][n - 1 - x])
+    );
+    assert_eq!(
+        render(&img.flip_vertical()),
+        expected(&|x, y| grid[n - 1 - y][x])
+    );
+}
+
+#[test]
+fn odd_size_transforms() {
+    let size = BWImageSize {
+        width: 13,
+        height: 21,
+    };
+    let mut img = BWImage::new(size, false);
+    for y in 0..size.height {
+        for x in 0..size.width {
+            img.set(x, y, (x * 7 + y * 3 + x * y) % 5 < 2);
+        }
+    }
+    let (w, h) = (size.width, size.height);
+
+    type Source<'a> = &'a dyn Fn(u32, u32) -> (u32, u32);
+    let checks: [(BWImage, Source); 6] = [
+        (img.transpose(), &|x, y| (y, x)),
+        (img.flip_horizontal(), &|x, y| (w - 1 - x, y)),
+        (img.flip_vertical(), &|x, y| (x, h - 1 - y)),
+        (img.rotate_90(), &|x, y| (y, h - 1 - x)),
+        (img.rotate_180(), &|x, y| (w - 1 - x, h - 1 - y)),
+        (img.rotate_270(), &|x, y| (w - 1 - y, x)),
+    ];
+    for (i, (out, source)) in checks.iter().enumerate() {
+        assert_eq!(
+            out.pixels.len() as u64,
+            out.size.get_padded_bytes_len(),
+            "{i}"
+        );
+        for y in 0..out.size.height {
+            for x in 0..out.size.width {
+                let (sx, sy) = source(x, y);
+                assert_eq!(out.get(x, y), img.get(sx, sy), "{i}: ({x}, {y})");
+            }
+        }
+        // padding bits stay clear
+        let mut cleared = out.clone();
+        cleared.fill(false);
+        for y in 0..out.size.height {
+            for x in 0..out.size.width {
+                cleared.set(x, y, out.get(x, y));
+            }
+        }
+        assert_eq!(cleared.pixels, out.pixels, "{i}");
+    }
+    assert_eq!(img.rotate_90().rotate_270().pixels, img.pixels);
+}
+
+#[test]
+fn scaling() {
+    let img = rust();
+    assert_eq!(img.resize(img.size).pixels, img.pixels);
+
+    for factor in [2, 3, 4] {
+        let up = img.scale_up(factor).unwrap();
+        assert_eq!(
+            up.size,
+            BWImageSize {
+                width: 64 * factor,
+                height: 64 * factor
+            }
+        );
+        assert_eq!(up.pixels, img.resize(up.size).pixels);
+        assert_eq!(up.scale_down(factor).pixels, img.pixels);
+        for (x, y) in [(0, 0), (17, 40), (63, 63), (31, 2)] {
+            assert_eq!(up.get(x * factor + factor - 1, y * factor), img.get(x, y));
+        }
+    }
+
+    assert!(img.scale_up(u32::MAX / 10).is_none());
+
+    // odd sizes, partial blocks at the edges
+    let size = BWImageSize {
+        width: 13,
+        height: 7,
+    };
+    let mut odd = BWImage::new(size, false);
+    for y in 0..7 {
+        for x in 0..13 {
+            odd.set(x, y, x < 5 || (x == 12 && y < 4));
+        }
+    }
+    let down = odd.scale_down(3);
+    assert_eq!(
+        down.size,
+        BWImageSize {
+            width: 5,
+            height: 3
+        }
+    );
+    for y in 0..3 {
+        // column 4 is a 1 pixel wide block, white in rows 0-3 only
+        let expected = [true, true, false, false, y < 1];
+        for (x, white) in expected.iter().enumerate() {
+            assert_eq!(down.get(x as u32, y), *white, "({x}, {y})");
+        }
+    }
+
+    let shrunk = img.resize(BWImageSize {
+        width: 21,
+        height: 50,
+    });
+    for y in 0..50 {
+        for x in 0..21 {
+            assert_eq!(shrunk.get(x, y), img.get(x * 64 / 21, y * 64 / 50));
+        }
+    }
+}
@@ -0,0 +1,206 @@
+use bw_img::{
+    img::BWImageSize,
+    iter_direction::{Horizontal, Vertical},
+    view::Alignment,
+    BWImage, IterOutput, NormalImage,
+};
+
+static RUST: &[u8] = include_bytes!("../assets/rust.png");
+
+fn rust() -> BWImage {
+    BWImage::parse(&NormalImage::new(&image::load_from_memory(RUST).unwrap())).unwrap()
+}
+
+fn collect<I: Iterator<Item = IterOutput>>(iter: I) -> Vec<(u8, usize)> {
+    iter.map(|out| match out {
+        IterOutput::Byte { byte, len } => (byte, len),
+        IterOutput::NewLine => (0, 0),
+    })
+    .collect()
+}
+
+#[test]
+fn crop_and_view() {
+    let img = rust();
+    let (x, y, width, height) = (5, 11, 29, 17);
+    let cropped = img.crop(x, y, width, height);
+    assert_eq!(cropped.size, BWImageSize { width, height });
+    assert_eq!(
+        cropped.pixels.len() as u64,
+        cropped.size.get_padded_bytes_len()
+    );
+    for cy in 0..height {
+        for cx in 0..width {
+            assert_eq!(cropped.get(cx, cy), img.get(x + cx, y + cy));
+        }
+    }
+
+    let view = img.view(x, y, width, height);
+    assert_eq!(
+        collect(view.iterator(Horizontal)),
+        collect(cropped.iterator(Horizontal))
+    );
+    assert_eq!(
+        collect(view.iterator(Vertical)),
+        collect(cropped.iterator(Vertical))
+    );
+
+    let nested = view.view(3, 2, 100, 100);
+    assert_eq!(nested.origin(), (8, 13));
+    assert_eq!(
+        nested.size(),
+        BWImageSize {
+            width: 26,
+            height: 15
+        }
+    );
+    assert_eq!(nested.get(0, 0), img.get(8, 13));
+    assert_eq!(nested.get_checked(26, 0), None);
+
+    // clipped to the image
+    assert_eq!(
+        img.crop(60, 60, 10, 10).size,
+        BWImageSize {
+            width: 4,
+            height: 4
+        }
+    );
+    assert_eq!(
+        img.crop(70, 0, 10, 10).size,
+        BWImageSize {
+            width: 0,
+            height: 10
+        }
+    );
+}
+
+#[test]
+fn pad_and_extend() {
+    let size = BWImageSize {
+        width: 3,
+        height: 2,
+    };
+    let img = BWImage::new(size, false);
+
+    let padded = img.pad(9, 1, 2, 0, true).unwrap();
+    assert_eq!(
+        padded.size,
+        BWImageSize {
+            width: 14,
+            height: 3
+        }
+    );
+    assert_eq!(
+        padded.pixels,
+        vec![0xff, 0xfc, 0xff, 0b1000_1100, 0xff, 0b1000_1100]
+    );
+
+    let centered = img.extend(
+        BWImageSize {
+            width: 7,
+            height: 4,
+        },
+        Alignment::Center,
+        true,
+    );
+    assert_eq!(
+        centered.pixels,
+        vec![0b1111_1110, 0b1100_0110, 0b1100_0110, 0b1111_1110]
+    );
+    let aligned = img
+        .pad_to_multiple(8, Alignment::BottomRight, true)
+        .unwrap();
+    assert_eq!(
+        aligned.size,
+        BWImageSize {
+            width: 8,
+            height: 8
+        }
+    );
+    assert_eq!(aligned.pixels[6..], [0b1111_1000, 0b1111_1000]);
+    assert!(aligned.pixels[..6].iter().all(|b| *b == 0xff));
+
+    // sizes overflowing u32
+    assert!(img.pad(u32::MAX - 3, 0, 1, 0, true).is_none());
+    assert!(img.pad(0, 0, 0, u32::MAX, true).is_none());
+
+    // larger images are clipped
+    let rust = rust();
+    let shrunk = rust.extend(
+        BWImageSize {
+            width: 20,
+            height: 20,
+        },
+        Alignment::Center,
+        true,
+    );
+    assert_eq!(shrunk.pixels, rust.crop(22, 22, 20, 20).pixels);
+    assert_eq!(
+        rust.pad(3, 5, 0, 0, false)
+            .unwrap()
+            .crop(3, 5, 64, 64)
+            .pixels,
+        rust.pixels
+    );
+}
+
+#[test]
+fn content_bounds_and_trim() {
+    let size = BWImageSize {
+        width: 30,
+        height: 12,
+    };
+    let mut img = BWImage::new(size, true);
+    assert_eq!(img.content_bounds(false), None);
+    assert_eq!(img.trim(false, 2), None);
+
+    img.set(9, 3, false);
+    img.set(21, 7, false);
+    img.set(15, 5, false);
+    let bounds = BWImageSize {
+        width: 13,
+        height: 5,
+    };
+    assert_eq!(img.content_bounds(false), Some(((9, 3), bounds)));
+    assert_eq!(
+        img.content_bounds(true),
+        Some(((0, 0), size)),
+        "the white background covers the image"
+    );
+
+    let trimmed = img.trim(false, 0).unwrap();
+    assert_eq!(trimmed.size, bounds);
+    assert!(!trimmed.get(0, 0) && !trimmed.get(12, 4) && !trimmed.get(6, 2));
+
+    // the padding is clipped to the image
+    let padded = img.trim(false, 4).unwrap();
+    assert_eq!(
+        padded.size,
+        BWImageSize {
+            width: 21,
+            height: 12
+        }
+    );
+    assert_eq!(padded.content_bounds(false), Some(((4, 3), bounds)));
+
+    // white content in the last byte of a row
+    let mut img = BWImage::new(size, false);
+    img.set(29, 11, true);
+    assert_eq!(
+        img.content_bounds(true),
+        Some((
+            (29, 11),
+            BWImageSize {
+                width: 1,
+                height: 1
+            }
+        ))
+    );
+    assert_eq!(
+        img.trim(true, 1).unwrap().size,
+        BWImageSize {
+            width: 2,
+            height: 2
+        }
+    );
+}